@@ -0,0 +1,74 @@
+use aoc_common::{parse::lines, ParseError, Result, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<Elf>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Elf::from_supply_list(input)
+    }
+
+    fn part_one(elves: &Self::Parsed) -> Result<Self::PartOne> {
+        let chosen = elves.iter().max_by_key(|elf| elf.supplies);
+        Ok(chosen.ok_or("No elves!")?.supplies)
+    }
+
+    fn part_two(elves: &Self::Parsed) -> Result<Self::PartTwo> {
+        let mut top_elves = elves.clone();
+        top_elves.sort_by_key(|elf| elf.supplies);
+        if top_elves.len() >= 3 {
+            Ok(top_elves
+                .iter()
+                .rev()
+                .take(3)
+                .fold(0, |acc, elf| acc + elf.supplies))
+        } else {
+            Err("Not enough elves!".into())
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Elf {
+    pub supplies: i64,
+}
+
+impl Elf {
+    pub fn from_supply_list(input: &str) -> Result<Vec<Elf>, ParseError> {
+        let mut elves = Vec::new();
+
+        let mut lines = lines(input).peekable();
+
+        loop {
+            let mut supplies = None;
+            loop {
+                match lines.next() {
+                    Some(line) if !line.text.is_empty() => {
+                        let number = line.parse(line.text, "number of calories").map_err(|e| {
+                            e.with_hint("each line holds calories of one item, elves are separated by empty lines")
+                        })?;
+                        supplies = match supplies {
+                            None => Some(number),
+                            Some(x) => Some(x + number),
+                        };
+                    }
+                    _ => break,
+                };
+            }
+            if let Some(supplies) = supplies {
+                elves.push(Elf { supplies });
+            }
+            if lines.peek().is_none() {
+                break;
+            }
+        }
+
+        Ok(elves)
+    }
+}
@@ -0,0 +1,184 @@
+use aoc_common::{
+    parse::{lines, Line},
+    ParseError, Result, Solution,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    type Parsed = StrategyGuide;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(StrategyGuide {
+            as_plays: load_rounds_from_str(input, interpret_oponent_you)?,
+            as_outcomes: load_rounds_from_str(input, interpret_oponent_outcome)?,
+        })
+    }
+
+    fn part_one(guide: &Self::Parsed) -> Result<Self::PartOne> {
+        Ok(guide
+            .as_plays
+            .iter()
+            .fold(0, |acc, round| acc + round.score()))
+    }
+
+    fn part_two(guide: &Self::Parsed) -> Result<Self::PartTwo> {
+        Ok(guide
+            .as_outcomes
+            .iter()
+            .fold(0, |acc, round| acc + round.score()))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Play {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Play {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+}
+
+impl TryFrom<&str> for Play {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "A" | "X" => Ok(Self::Rock),
+            "B" | "Y" => Ok(Self::Paper),
+            "C" | "Z" => Ok(Self::Scissors),
+            _ => Err(format!("Invalid play: {value}")),
+        }
+    }
+}
+
+pub enum Outcome {
+    Victory,
+    Draw,
+    Defeat,
+}
+
+impl Outcome {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Victory => 6,
+            Self::Draw => 3,
+            Self::Defeat => 0,
+        }
+    }
+}
+
+impl TryFrom<&str> for Outcome {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "X" => Ok(Self::Defeat),
+            "Y" => Ok(Self::Draw),
+            "Z" => Ok(Self::Victory),
+            _ => Err(format!("Invalid outcome: {value}")),
+        }
+    }
+}
+
+impl From<&Round> for Outcome {
+    fn from(value: &Round) -> Self {
+        match (&value.oponent, &value.you) {
+            (Play::Rock, Play::Scissors)
+            | (Play::Paper, Play::Rock)
+            | (Play::Scissors, Play::Paper) => Self::Defeat,
+            (Play::Rock, Play::Rock)
+            | (Play::Paper, Play::Paper)
+            | (Play::Scissors, Play::Scissors) => Self::Draw,
+            (Play::Rock, Play::Paper)
+            | (Play::Paper, Play::Scissors)
+            | (Play::Scissors, Play::Rock) => Self::Victory,
+        }
+    }
+}
+
+pub struct Round {
+    pub oponent: Play,
+    pub you: Play,
+}
+
+impl Round {
+    pub fn score(&self) -> u64 {
+        let pick = self.you.value();
+        let outcome = Outcome::from(self).value();
+        pick + outcome
+    }
+}
+
+/// The guide's second column read either as your play or as the desired outcome.
+pub struct StrategyGuide {
+    pub as_plays: Vec<Round>,
+    pub as_outcomes: Vec<Round>,
+}
+
+pub fn load_rounds_from_str(
+    input: &str,
+    interpret_fn: impl Fn(&Line, &str, &str) -> Result<Round, ParseError>,
+) -> Result<Vec<Round>, ParseError> {
+    let mut rounds = Vec::new();
+
+    for line in lines(input) {
+        let round = load_round(&line, &interpret_fn)
+            .map_err(|e| e.with_hint("each round is two letters, like `A Y`"))?;
+        rounds.push(round);
+    }
+
+    Ok(rounds)
+}
+
+fn load_round(
+    line: &Line,
+    interpret_fn: impl Fn(&Line, &str, &str) -> Result<Round, ParseError>,
+) -> Result<Round, ParseError> {
+    let mut words = line.words();
+    let a = line.expect(words.next(), "oponent's play")?;
+    let b = line.expect(words.next(), "second column")?;
+    line.expect_end(words.next())?;
+    interpret_fn(line, a, b)
+}
+
+pub fn interpret_oponent_you(line: &Line, a: &str, b: &str) -> Result<Round, ParseError> {
+    Ok(Round {
+        oponent: a
+            .try_into()
+            .map_err(|_| line.error(a, "oponent's play A, B or C"))?,
+        you: b
+            .try_into()
+            .map_err(|_| line.error(b, "your play X, Y or Z"))?,
+    })
+}
+
+pub fn interpret_oponent_outcome(line: &Line, a: &str, b: &str) -> Result<Round, ParseError> {
+    let oponent = Play::try_from(a).map_err(|_| line.error(a, "oponent's play A, B or C"))?;
+    let outcome = Outcome::try_from(b).map_err(|_| line.error(b, "desired outcome X, Y or Z"))?;
+    let you = match (&oponent, &outcome) {
+        (Play::Rock, Outcome::Victory)
+        | (Play::Paper, Outcome::Draw)
+        | (Play::Scissors, Outcome::Defeat) => Play::Paper,
+        (Play::Paper, Outcome::Victory)
+        | (Play::Scissors, Outcome::Draw)
+        | (Play::Rock, Outcome::Defeat) => Play::Scissors,
+        (Play::Scissors, Outcome::Victory)
+        | (Play::Rock, Outcome::Draw)
+        | (Play::Paper, Outcome::Defeat) => Play::Rock,
+    };
+    Ok(Round { oponent, you })
+}
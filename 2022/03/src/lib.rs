@@ -0,0 +1,236 @@
+use aoc_common::{
+    parse::{lines, Line},
+    ParseError, Result, Solution,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    type Parsed = Vec<Rucksack>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        lines(input)
+            .map(|line| Rucksack::from_line(&line))
+            .collect()
+    }
+
+    fn part_one(rucksacks: &Self::Parsed) -> Result<Self::PartOne> {
+        let mut score = 0;
+        for rucksack in rucksacks {
+            score += rucksack
+                .find_match_in_compartments()
+                .ok_or("Rucksack has no item in both compartments")?
+                .priority();
+        }
+        Ok(score)
+    }
+
+    fn part_two(rucksacks: &Self::Parsed) -> Result<Self::PartTwo> {
+        let mut score = 0;
+        for group in rucksacks.chunks_exact(3) {
+            score += Rucksack::find_match_in_group(&group[0], &group[1], &group[2])
+                .ok_or("Group has no common item")?
+                .priority();
+        }
+        Ok(score)
+    }
+}
+
+pub trait Item {
+    fn priority(&self) -> u64;
+    fn from_priority(priority: u64) -> Self;
+}
+
+impl Item for char {
+    fn priority(&self) -> u64 {
+        match self {
+            x if x.is_ascii_lowercase() => *x as u8 - b'a' + 1,
+            x if x.is_ascii_uppercase() => *x as u8 - b'A' + 27,
+            x => panic!("Invalid item: {x}"),
+        }
+        .into()
+    }
+
+    fn from_priority(priority: u64) -> Self {
+        match priority {
+            0 => panic!("Priority out of range: {priority}"),
+            1..=26 => ('a' as u64 + priority - 1) as u8 as char,
+            27..=52 => ('A' as u64 + priority - 27) as u8 as char,
+            _ => panic!("Priority out of range: {priority}"),
+        }
+    }
+}
+
+pub struct Compartment {
+    pub items: [bool; 52],
+}
+
+impl Default for Compartment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Compartment {
+    pub fn new() -> Self {
+        Self { items: [false; 52] }
+    }
+
+    pub fn put(&mut self, item: char) {
+        self.items[item.priority() as usize - 1] = true;
+    }
+
+    pub fn check(&self, item: char) -> bool {
+        self.items[item.priority() as usize - 1]
+    }
+}
+
+#[derive(Debug)]
+pub struct Rucksack {
+    pub items: String,
+}
+
+impl Rucksack {
+    /// Panics unless `items` is an even number of ASCII characters, see `from_line`.
+    pub fn new(items: &str) -> Self {
+        assert!(items.is_ascii());
+        assert!(items.len().is_multiple_of(2));
+        Self {
+            items: items.into(),
+        }
+    }
+
+    pub fn from_line(line: &Line) -> Result<Self, ParseError> {
+        let invalid = line
+            .text
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_alphabetic());
+        let hint = "a rucksack is a line of letters, split evenly into two compartments";
+        if let Some((i, c)) = invalid {
+            let item = &line.text[i..i + c.len_utf8()];
+            return Err(line.error(item, "item a-z or A-Z").with_hint(hint));
+        }
+        if !line.text.len().is_multiple_of(2) {
+            return Err(line
+                .error(line.text, "an even number of items")
+                .with_hint(hint));
+        }
+        Ok(Self::new(line.text))
+    }
+
+    pub fn find_match_in_compartments(&self) -> Option<char> {
+        let half = self.items.len() / 2;
+        let left = self.items.chars().take(half);
+        let mut left_compartment = Compartment::new();
+        for item in left {
+            left_compartment.put(item);
+        }
+        self.items
+            .chars()
+            .skip(half)
+            .find(|item| left_compartment.check(*item))
+    }
+
+    pub fn find_match_in_group(fst: &Self, snd: &Self, trd: &Self) -> Option<char> {
+        let mut matches = [0; 52];
+        {
+            let mut compartment = Compartment::new();
+            for item in fst.items.chars() {
+                compartment.put(item);
+            }
+            for c in 'a'..='z' {
+                if compartment.check(c) {
+                    matches[c.priority() as usize - 1] += 1;
+                }
+            }
+            for c in 'A'..='Z' {
+                if compartment.check(c) {
+                    matches[c.priority() as usize - 1] += 1;
+                }
+            }
+        }
+        {
+            let mut compartment = Compartment::new();
+            for item in snd.items.chars() {
+                compartment.put(item);
+            }
+            for c in 'a'..='z' {
+                if compartment.check(c) {
+                    matches[c.priority() as usize - 1] += 1;
+                }
+            }
+            for c in 'A'..='Z' {
+                if compartment.check(c) {
+                    matches[c.priority() as usize - 1] += 1;
+                }
+            }
+        }
+        {
+            let mut compartment = Compartment::new();
+            for item in trd.items.chars() {
+                compartment.put(item);
+            }
+            for c in 'a'..='z' {
+                if compartment.check(c) {
+                    matches[c.priority() as usize - 1] += 1;
+                }
+            }
+            for c in 'A'..='Z' {
+                if compartment.check(c) {
+                    matches[c.priority() as usize - 1] += 1;
+                }
+            }
+        }
+        matches
+            .iter()
+            .position(|m| *m == 3)
+            .map(|i| char::from_priority(i as u64 + 1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Item, Rucksack};
+
+    #[test]
+    fn test_priority() {
+        assert!('p'.priority() == 16);
+        assert!('L'.priority() == 38);
+        assert!('P'.priority() == 42);
+        assert!('v'.priority() == 22);
+        assert!('t'.priority() == 20);
+        assert!('s'.priority() == 19);
+        assert!('Z'.priority() == 52);
+    }
+
+    #[test]
+    fn test_from_priority() {
+        assert!(char::from_priority(16) == 'p');
+        assert!(char::from_priority(38) == 'L');
+        assert!(char::from_priority(42) == 'P');
+        assert!(char::from_priority(22) == 'v');
+        assert!(char::from_priority(20) == 't');
+        assert!(char::from_priority(19) == 's');
+        assert!(char::from_priority(52) == 'Z');
+    }
+
+    #[test]
+    fn test_find_match_in_group() {
+        let fst = Rucksack::new("qpwerq");
+        let snd = Rucksack::new("aspdfa");
+        let trd = Rucksack::new("zxcpvz");
+        let m = Rucksack::find_match_in_group(&fst, &snd, &trd);
+        assert_eq!(m, Some('p'));
+
+        let fst = Rucksack::new("qZwerq");
+        let snd = Rucksack::new("asZdfa");
+        let trd = Rucksack::new("zxcZvz");
+        let m = Rucksack::find_match_in_group(&fst, &snd, &trd);
+        assert_eq!(m, Some('Z'));
+    }
+}
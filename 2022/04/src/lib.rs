@@ -0,0 +1,93 @@
+use aoc_common::{
+    interval::Interval,
+    parse::{lines, Line},
+    ParseError, Result, Solution,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<Pair>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_assignments(input)
+    }
+
+    fn part_one(pairs: &Self::Parsed) -> Result<Self::PartOne> {
+        Ok(pairs
+            .iter()
+            .filter(|pair| pair.left.contains(&pair.right) || pair.right.contains(&pair.left))
+            .count())
+    }
+
+    fn part_two(pairs: &Self::Parsed) -> Result<Self::PartTwo> {
+        Ok(pairs
+            .iter()
+            .filter(|pair| pair.left.overlaps(&pair.right))
+            .count())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Assignment {
+    pub sections: Interval<usize>,
+}
+
+impl Assignment {
+    pub fn from_token(line: &Line, token: &str) -> Result<Self, ParseError> {
+        let (start, last) = token
+            .split_once('-')
+            .ok_or_else(|| line.error(token, "assignment x-y"))?;
+        let start = line.parse(start, "section number")?;
+        // Both ends are assigned, the interval stops one section later.
+        let end = line
+            .parse::<usize>(last, "section number")?
+            .checked_add(1)
+            .ok_or_else(|| line.error(last, format!("section number below {}", usize::MAX)))?;
+        let sections = Interval::new(start, end)
+            .ok_or_else(|| line.error(token, "assignment x-y with x <= y"))?;
+        Ok(Self { sections })
+    }
+
+    pub fn contains(&self, other: &Self) -> bool {
+        self.sections.covers(&other.sections)
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.sections.overlaps(&other.sections)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Pair {
+    pub left: Assignment,
+    pub right: Assignment,
+}
+
+impl Pair {
+    pub fn from_line(line: &Line) -> Result<Self, ParseError> {
+        let mut assignments = line.text.split(',');
+        let left = line.expect(assignments.next(), "assignment x-y")?;
+        let right = line.expect(assignments.next(), "second assignment x-y")?;
+        line.expect_end(assignments.next())?;
+        Ok(Self {
+            left: Assignment::from_token(line, left)?,
+            right: Assignment::from_token(line, right)?,
+        })
+    }
+}
+
+pub fn parse_assignments(input: &str) -> Result<Vec<Pair>, ParseError> {
+    let mut pairs = Vec::new();
+    for line in lines(input) {
+        let pair = Pair::from_line(&line)
+            .map_err(|e| e.with_hint("each line is a pair of section ranges, like `2-4,6-8`"))?;
+        pairs.push(pair);
+    }
+    Ok(pairs)
+}
@@ -0,0 +1,166 @@
+use aoc_common::{
+    parse::{lines, Line},
+    ParseError, Result, Solution,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    type Parsed = Procedure;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut lines = lines(input);
+        let stacks_info = lines
+            .by_ref()
+            .take_while(|l| !l.text.is_empty())
+            .collect::<Vec<_>>();
+
+        let drawing_hint = |e: ParseError| {
+            e.with_hint("the drawing of crates ends with stack numbers, like ` 1   2   3 `")
+        };
+        let (numbers, rows) = stacks_info
+            .split_last()
+            .ok_or_else(|| ParseError::new(1, 1, "", "stacks drawing"))
+            .map_err(drawing_hint)?;
+        let columns = count_columns(numbers).map_err(drawing_hint)?;
+
+        let mut stacks = Stacks::new(columns);
+        for row in rows.iter().rev() {
+            stacks.push_row(row).map_err(drawing_hint)?;
+        }
+
+        let moves = lines
+            .map(|line| Movement::from_line(&line, columns))
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.with_hint("moves look like `move 1 from 2 to 1`"))?;
+
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part_one(procedure: &Self::Parsed) -> Result<Self::PartOne> {
+        let mut stacks = procedure.stacks.clone();
+        for m in &procedure.moves {
+            stacks.movement(m);
+        }
+        Ok(stacks.tops())
+    }
+
+    fn part_two(procedure: &Self::Parsed) -> Result<Self::PartTwo> {
+        let mut stacks = procedure.stacks.clone();
+        for m in &procedure.moves {
+            stacks.movement_9001(m);
+        }
+        Ok(stacks.tops())
+    }
+}
+
+pub fn count_columns(line: &Line) -> Result<usize, ParseError> {
+    let len = line.text.len();
+    if len < 3 {
+        return Err(line.error(line.text, "stack numbers"));
+    }
+    Ok(((len - 3) / 4) + 1)
+}
+
+#[derive(Clone)]
+pub struct Stacks {
+    pub stacks: Vec<Vec<char>>,
+    pub buffer: Vec<char>,
+}
+
+impl Stacks {
+    pub fn new(size: usize) -> Self {
+        let mut stacks = Vec::new();
+        for _ in 0..size {
+            stacks.push(Vec::new());
+        }
+        Self {
+            stacks,
+            buffer: Vec::new(),
+        }
+    }
+
+    pub fn push_row(&mut self, row: &Line) -> Result<(), ParseError> {
+        let columns = self.stacks.len();
+        for ((i, c), index) in row.text.char_indices().skip(1).step_by(4).zip(0..) {
+            if c.is_ascii_uppercase() {
+                let stack = self.stacks.get_mut(index).ok_or_else(|| {
+                    row.error(
+                        &row.text[i..i + 1],
+                        format!("crate on one of {columns} stacks"),
+                    )
+                })?;
+                stack.push(c);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn movement(&mut self, movement: &Movement) {
+        for _ in 0..movement.repeats {
+            if let Some(x) = self.stacks[movement.from - 1].pop() {
+                self.stacks[movement.to - 1].push(x);
+            }
+        }
+    }
+
+    pub fn movement_9001(&mut self, movement: &Movement) {
+        for _ in 0..movement.repeats {
+            if let Some(x) = self.stacks[movement.from - 1].pop() {
+                self.buffer.push(x);
+            }
+        }
+        for _ in 0..movement.repeats {
+            if let Some(x) = self.buffer.pop() {
+                self.stacks[movement.to - 1].push(x);
+            }
+        }
+    }
+
+    pub fn tops(&self) -> String {
+        self.stacks
+            .iter()
+            .map(|stack| stack.last().unwrap_or(&' '))
+            .collect()
+    }
+}
+
+pub struct Procedure {
+    pub stacks: Stacks,
+    pub moves: Vec<Movement>,
+}
+
+pub struct Movement {
+    pub repeats: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Movement {
+    pub fn from_line(line: &Line, stacks: usize) -> Result<Self, ParseError> {
+        let mut words = line.words();
+        line.expect_literal(words.next(), "move")?;
+        let repeats = line.expect(words.next(), "number of crates")?;
+        let repeats = line.parse(repeats, "number of crates")?;
+        line.expect_literal(words.next(), "from")?;
+        let from = Self::parse_stack(line, words.next(), stacks)?;
+        line.expect_literal(words.next(), "to")?;
+        let to = Self::parse_stack(line, words.next(), stacks)?;
+        line.expect_end(words.next())?;
+        Ok(Self { repeats, from, to })
+    }
+
+    fn parse_stack(line: &Line, token: Option<&str>, stacks: usize) -> Result<usize, ParseError> {
+        let expected = format!("stack number 1-{stacks}");
+        let token = line.expect(token, &expected)?;
+        match line.parse(token, &expected)? {
+            stack @ 1.. if stack <= stacks => Ok(stack),
+            _ => Err(line.error(token, expected)),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::BTreeSet;
+
+use aoc_common::{parse::lines, ParseError, Result, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    type Parsed = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_datastream(input)
+            .map_err(|e| e.with_hint("the datastream is a single line of letters a-z"))
+    }
+
+    fn part_one(input: &Self::Parsed) -> Result<Self::PartOne> {
+        let start_packet = find_sequence_of_length(input, 4).map(|x| x + 4);
+        Ok(start_packet.ok_or("Start of packet not found!")?)
+    }
+
+    fn part_two(input: &Self::Parsed) -> Result<Self::PartTwo> {
+        let start_message = find_sequence_of_length(input, 14).map(|x| x + 14);
+        Ok(start_message.ok_or("Start of message not found!")?)
+    }
+}
+
+pub fn parse_datastream(input: &str) -> Result<String, ParseError> {
+    let mut lines = lines(input);
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "", "datastream"))?;
+    let invalid = line
+        .text
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_lowercase());
+    if let Some((i, c)) = invalid {
+        return Err(line.error(&line.text[i..i + c.len_utf8()], "letter a-z"));
+    }
+    if let Some(extra) = lines.next() {
+        return Err(extra.error(extra.text, "end of input"));
+    }
+    Ok(line.text.into())
+}
+
+pub fn find_sequence_of_length(input: &str, len: usize) -> Option<usize> {
+    for index in 0..=input.len().checked_sub(len)? {
+        let slice = &input[index..index + len];
+        if has_unique_chars(slice) {
+            return Some(index);
+        }
+    }
+    None
+}
+
+pub fn has_unique_chars(slice: &str) -> bool {
+    let mut set = BTreeSet::new();
+    for char in slice.chars() {
+        if !set.insert(char) {
+            return false;
+        }
+    }
+    true
+}
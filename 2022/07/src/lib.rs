@@ -0,0 +1,277 @@
+use std::collections::HashMap;
+
+use aoc_common::{
+    parse::{lines, Line},
+    ParseError, Result, Solution,
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+
+    type Parsed = Tree;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let commands = parse_commands(input)?;
+        discover_file_tree(&commands)
+    }
+
+    fn part_one(tree: &Self::Parsed) -> Result<Self::PartOne> {
+        let sizes = tree.get_dir_sizes();
+        Ok(sizes.iter().filter(|x| **x <= 100000).sum::<u64>())
+    }
+
+    fn part_two(tree: &Self::Parsed) -> Result<Self::PartTwo> {
+        let sizes = tree.get_dir_sizes();
+        let total = *sizes.last().ok_or("File tree has no root directory")?;
+        let missing = u64::try_from((total as i64) - 40000000)
+            .map_err(|_| "Used space is below 40000000, nothing needs deleting")?;
+        let big_enough = sizes.iter().filter(|x| **x >= missing).min();
+        Ok(*big_enough.ok_or("No dir is big enough")?)
+    }
+}
+
+#[derive(Debug)]
+pub struct Tree {
+    pub root: Node,
+}
+
+impl Default for Tree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tree {
+    pub fn new() -> Self {
+        Self {
+            root: Node::empty_dir(),
+        }
+    }
+
+    /// The directory at `path`, `None` if it wasn't listed or is a file.
+    pub fn get(&mut self, path: &AbsolutePath) -> Option<&mut HashMap<String, Node>> {
+        let mut current = &mut self.root;
+        for component in &path.components {
+            match current {
+                Node::Dir(dir) => current = dir.get_mut(component)?,
+                Node::File(_) => return None,
+            }
+        }
+        match current {
+            Node::Dir(dir) => Some(dir),
+            Node::File(_) => None,
+        }
+    }
+
+    pub fn get_dir_sizes(&self) -> Vec<u64> {
+        let mut result = Vec::new();
+        self.root.get_dir_sizes(&mut result);
+        result
+    }
+}
+
+#[derive(Debug)]
+pub enum Node {
+    File(u64),
+    Dir(HashMap<String, Node>),
+}
+
+impl Node {
+    pub fn empty_dir() -> Self {
+        Self::Dir(HashMap::new())
+    }
+
+    /// Total size of the node, pushing the size of every directory in it
+    /// to `result`, the node itself last.
+    pub fn get_dir_sizes(&self, result: &mut Vec<u64>) -> u64 {
+        match self {
+            Self::File(size) => *size,
+            Self::Dir(dir) => {
+                let mut size = 0;
+                dir.values().for_each(|c| match c {
+                    Node::File(s) => {
+                        size += s;
+                    }
+                    Node::Dir(_) => {
+                        let s = c.get_dir_sizes(result);
+                        size += s;
+                    }
+                });
+                result.push(size);
+                size
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AbsolutePath {
+    pub components: Vec<String>,
+}
+
+impl Default for AbsolutePath {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AbsolutePath {
+    pub fn new() -> Self {
+        Self {
+            components: Vec::new(),
+        }
+    }
+
+    pub fn cd(&mut self, cd: &CD) {
+        match cd {
+            CD::Root => {
+                self.components.clear();
+            }
+            CD::Back => {
+                self.components.pop();
+            }
+            CD::Forward(x) => {
+                self.components.push(x.clone());
+            }
+        }
+    }
+}
+
+/// A command with the line it was typed on.
+#[derive(Debug)]
+pub struct Command<'a> {
+    pub line: Line<'a>,
+    pub kind: CommandKind,
+}
+
+#[derive(Debug)]
+pub enum CommandKind {
+    CD(CD),
+    LS(Vec<Entry>),
+}
+
+#[derive(Debug)]
+pub enum CD {
+    Root,
+    Back,
+    Forward(String),
+}
+
+#[derive(Debug)]
+pub enum Entry {
+    File { name: String, size: u64 },
+    Dir { name: String },
+}
+
+impl Entry {
+    pub fn from_line(line: &Line) -> Result<Self, ParseError> {
+        let hint = |e: ParseError| e.with_hint("`ls` output lines are `dir NAME` or `SIZE NAME`");
+        let mut words = line.words();
+        let kind = line
+            .expect(words.next(), "'dir' or file size")
+            .map_err(hint)?;
+        let name = line.expect(words.next(), "entry name").map_err(hint)?;
+        line.expect_end(words.next()).map_err(hint)?;
+        Ok(match kind {
+            "dir" => Entry::Dir { name: name.into() },
+            size => Entry::File {
+                name: name.into(),
+                size: line.parse(size, "'dir' or file size").map_err(hint)?,
+            },
+        })
+    }
+}
+
+pub fn parse_commands(str: &str) -> Result<Vec<Command<'_>>, ParseError> {
+    let mut commands = Vec::new();
+    let mut lines = lines(str).peekable();
+    let hint = |e: ParseError| e.with_hint("commands are `$ cd DIR` or `$ ls`");
+    while let Some(line) = lines.next() {
+        let mut words = line.words();
+        line.expect_literal(words.next(), "$").map_err(hint)?;
+        let command = line
+            .expect(words.next(), "command cd or ls")
+            .map_err(hint)?;
+        let kind = match command {
+            "cd" => {
+                let target = line.expect(words.next(), "directory").map_err(hint)?;
+                line.expect_end(words.next()).map_err(hint)?;
+                match target {
+                    "/" => CommandKind::CD(CD::Root),
+                    ".." => CommandKind::CD(CD::Back),
+                    component => CommandKind::CD(CD::Forward(component.into())),
+                }
+            }
+            "ls" => {
+                line.expect_end(words.next()).map_err(hint)?;
+                let mut entries = Vec::new();
+                while let Some(entry) = lines.next_if(|l| !l.text.starts_with('$')) {
+                    entries.push(Entry::from_line(&entry)?);
+                }
+                CommandKind::LS(entries)
+            }
+            _ => return Err(hint(line.error(command, "command cd or ls"))),
+        };
+        commands.push(Command { line, kind });
+    }
+    Ok(commands)
+}
+
+/// Replays the commands, failing on a `cd` into a directory no earlier
+/// `ls` listed.
+pub fn discover_file_tree(commands: &[Command]) -> Result<Tree, ParseError> {
+    let mut tree = Tree::new();
+    let mut path = AbsolutePath::new();
+    for Command { line, kind } in commands {
+        let expected = "directory listed by a previous `ls`";
+        match kind {
+            CommandKind::CD(cd) => {
+                path.cd(cd);
+                if tree.get(&path).is_none() {
+                    let target = line.words().last().unwrap_or(line.text);
+                    return Err(line.error(target, expected));
+                }
+            }
+            CommandKind::LS(entries) => {
+                let dir = tree
+                    .get(&path)
+                    .ok_or_else(|| line.error(line.text, expected))?;
+                for entry in entries {
+                    match entry {
+                        Entry::File { name, size } => {
+                            dir.insert(name.into(), Node::File(*size));
+                        }
+                        Entry::Dir { name } => {
+                            dir.entry(name.into()).or_insert(Node::empty_dir());
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(tree)
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Solution;
+
+    use crate::Day07;
+
+    #[test]
+    fn test_unlisted_directories() {
+        let err = Day07::parse("$ cd a\n$ ls\n1 b\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 6, "a"));
+        let err = Day07::parse("$ ls\n12 a\n$ cd a\n$ ls\n1 b\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (3, "directory listed by a previous `ls`")
+        );
+        assert!(Day07::parse("$ ls\ndir a\n$ cd a\n$ cd ..\n").is_ok());
+    }
+}
@@ -0,0 +1,139 @@
+use aoc_common::{
+    grid::{Direction, Grid},
+    ParseError, Result, Solution,
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+
+    type Parsed = Forest;
+    type PartOne = usize;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Forest::from_input(input)
+            .map_err(|e| e.with_hint("the forest is a rectangle of tree heights 0-9"))
+    }
+
+    fn part_one(forest: &Self::Parsed) -> Result<Self::PartOne> {
+        Ok(forest.trees.iter().filter(|(_, t)| t.visible).count())
+    }
+
+    fn part_two(forest: &Self::Parsed) -> Result<Self::PartTwo> {
+        let best = forest.trees.iter().map(|(_, t)| t.scenic_score).max();
+        Ok(best.ok_or("Forest is empty")?)
+    }
+}
+
+#[derive(Debug)]
+pub struct Forest {
+    pub trees: Grid<Tree>,
+}
+
+impl Forest {
+    pub fn from_input(str: &str) -> Result<Self, ParseError> {
+        let trees = Grid::parse(str, "tree height 0-9", |c| c.to_digit(10).map(Tree::new))?;
+        let mut forest = Self { trees };
+        forest.fill_neighbours();
+        forest.calculate_visibilities();
+        forest.calculate_scenic_scores();
+        Ok(forest)
+    }
+
+    pub fn fill_neighbours(&mut self) {
+        for y in 0..self.trees.height() {
+            for x in 0..self.trees.width() {
+                let max = |direction| {
+                    self.trees
+                        .ray((x, y), direction)
+                        .map(|(_, tree)| tree.height)
+                        .max()
+                };
+                let (left, right) = (max(Direction::Left), max(Direction::Right));
+                let (up, down) = (max(Direction::Up), max(Direction::Down));
+                let tree = &mut self.trees[(x, y)];
+                tree.max_left = left;
+                tree.max_right = right;
+                tree.max_up = up;
+                tree.max_down = down;
+            }
+        }
+    }
+
+    pub fn calculate_visibilities(&mut self) {
+        for y in 0..self.trees.height() {
+            for x in 0..self.trees.width() {
+                self.trees[(x, y)].calc_is_visible();
+            }
+        }
+    }
+
+    pub fn calculate_scenic_scores(&mut self) {
+        for y in 0..self.trees.height() {
+            for x in 0..self.trees.width() {
+                self.calculate_scenic_score(x, y);
+            }
+        }
+    }
+
+    pub fn calculate_scenic_score(&mut self, x: usize, y: usize) {
+        let score = Direction::ORTHOGONAL
+            .into_iter()
+            .map(|direction| self.viewing_distance(x, y, direction))
+            .product();
+        self.trees[(x, y)].scenic_score = score;
+    }
+
+    /// Trees seen from `(x, y)` looking in `direction`, up to and including
+    /// the first one at least as tall.
+    pub fn viewing_distance(&self, x: usize, y: usize, direction: Direction) -> u32 {
+        let height = self.trees[(x, y)].height;
+        let mut distance = 0;
+        for (_, tree) in self.trees.ray((x, y), direction) {
+            distance += 1;
+            if tree.height >= height {
+                break;
+            }
+        }
+        distance
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&Tree> {
+        self.trees.get((x, y))
+    }
+}
+
+#[derive(Debug)]
+pub struct Tree {
+    pub height: u32,
+    pub max_right: Option<u32>,
+    pub max_left: Option<u32>,
+    pub max_up: Option<u32>,
+    pub max_down: Option<u32>,
+    pub visible: bool,
+    pub scenic_score: u32,
+}
+
+impl Tree {
+    pub fn new(height: u32) -> Self {
+        Self {
+            height,
+            max_right: None,
+            max_left: None,
+            max_up: None,
+            max_down: None,
+            visible: false,
+            scenic_score: 0,
+        }
+    }
+
+    pub fn calc_is_visible(&mut self) {
+        self.visible = self.max_right.is_none_or(|t| t < self.height)
+            || self.max_left.is_none_or(|t| t < self.height)
+            || self.max_up.is_none_or(|t| t < self.height)
+            || self.max_down.is_none_or(|t| t < self.height);
+    }
+}
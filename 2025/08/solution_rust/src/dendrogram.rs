@@ -0,0 +1,176 @@
+//! The order circuits were merged in, as a single-linkage dendrogram.
+
+use std::fmt::Write;
+
+use crate::CircuitMap;
+
+/// Two clusters joined into a new one. Clusters are numbered like SciPy's
+/// linkage matrices: boxes are `0..n` and merge `i` makes cluster `n + i`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Merge {
+    pub left: usize,
+    pub right: usize,
+    /// Kept exact, `distance` is what the outputs show.
+    pub distance_squared: u128,
+    /// Number of boxes in the merged cluster.
+    pub size: usize,
+}
+
+impl Merge {
+    pub fn distance(&self) -> f64 {
+        (self.distance_squared as f64).sqrt()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dendrogram {
+    pub leaves: usize,
+    pub merges: Vec<Merge>,
+}
+
+impl Dendrogram {
+    /// Replays the connections `circuits` accepted, shortest first, so merge
+    /// distances never decrease even if some connections were made out of
+    /// order. If they don't join everything, the result is a forest of
+    /// several trees.
+    pub fn new(circuits: &CircuitMap) -> Self {
+        let leaves = circuits.boxes.len();
+        let mut parents = (0..leaves).collect::<Vec<_>>();
+        // Cluster and size standing for every root box.
+        let mut clusters = (0..leaves).map(|leaf| (leaf, 1)).collect::<Vec<_>>();
+        let find = |parents: &mut Vec<usize>, mut junction_box: usize| {
+            while parents[junction_box] != junction_box {
+                parents[junction_box] = parents[parents[junction_box]];
+                junction_box = parents[junction_box];
+            }
+            junction_box
+        };
+
+        let mut accepted = circuits.accepted().to_vec();
+        accepted.sort_by_key(|&(_, distance_squared)| distance_squared);
+        let mut merges = Vec::new();
+        for ((from, to), distance_squared) in accepted {
+            let (from, to) = (find(&mut parents, from), find(&mut parents, to));
+            let ((a, a_size), (b, b_size)) = (clusters[from], clusters[to]);
+            parents[from] = to;
+            clusters[to] = (leaves + merges.len(), a_size + b_size);
+            merges.push(Merge {
+                left: a.min(b),
+                right: a.max(b),
+                distance_squared,
+                size: a_size + b_size,
+            });
+        }
+        Self { leaves, merges }
+    }
+
+    /// Height of a cluster, the distance of the merge that made it.
+    fn height(&self, cluster: usize) -> f64 {
+        match cluster.checked_sub(self.leaves) {
+            Some(merge) => self.merges[merge].distance(),
+            None => 0.0,
+        }
+    }
+
+    /// Clusters that weren't merged into any other, in increasing order.
+    fn roots(&self) -> Vec<usize> {
+        let mut merged = vec![false; self.leaves + self.merges.len()];
+        for merge in &self.merges {
+            merged[merge.left] = true;
+            merged[merge.right] = true;
+        }
+        (0..merged.len()).filter(|&c| !merged[c]).collect()
+    }
+
+    /// A header and one line per merge, in order.
+    pub fn text(&self) -> String {
+        let mut text = String::from("cluster left right distance size\n");
+        for (i, merge) in self.merges.iter().enumerate() {
+            let _ = writeln!(
+                text,
+                "{} {} {} {} {}",
+                self.leaves + i,
+                merge.left,
+                merge.right,
+                merge.distance(),
+                merge.size
+            );
+        }
+        text
+    }
+
+    /// One Newick tree per line, leaves named by box index and branch
+    /// lengths the differences of merge distances.
+    pub fn newick(&self) -> String {
+        enum Step {
+            Cluster(usize),
+            Text(&'static str),
+            /// Branch from a cluster up to a parent at the given height.
+            Length(usize, f64),
+        }
+
+        let mut newick = String::new();
+        for root in self.roots() {
+            // Written with an explicit stack, chains can be as deep as the
+            // number of boxes.
+            let mut steps = vec![Step::Cluster(root)];
+            while let Some(step) = steps.pop() {
+                match step {
+                    Step::Cluster(leaf) if leaf < self.leaves => {
+                        let _ = write!(newick, "{leaf}");
+                    }
+                    Step::Cluster(cluster) => {
+                        let merge = &self.merges[cluster - self.leaves];
+                        let height = self.height(cluster);
+                        newick.push('(');
+                        steps.extend([
+                            Step::Text(")"),
+                            Step::Length(merge.right, height),
+                            Step::Cluster(merge.right),
+                            Step::Text(","),
+                            Step::Length(merge.left, height),
+                            Step::Cluster(merge.left),
+                        ]);
+                    }
+                    Step::Text(text) => newick.push_str(text),
+                    Step::Length(cluster, parent) => {
+                        let _ = write!(newick, ":{}", parent - self.height(cluster));
+                    }
+                }
+            }
+            newick.push_str(";\n");
+        }
+        newick
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CircuitMap, JunctionBox, dendrogram::Dendrogram};
+
+    #[test]
+    fn test_dendrogram() {
+        let boxes = [0, 1, 3, 10].map(|x| JunctionBox { x, y: 0, z: 0 });
+        let mut circuits = CircuitMap::new(&boxes);
+        circuits.connect_until(2);
+        let dendrogram = Dendrogram::new(&circuits);
+        assert_eq!(
+            dendrogram.text(),
+            "cluster left right distance size\n4 0 1 1 2\n5 2 4 2 3\n"
+        );
+        assert_eq!(dendrogram.newick(), "3;\n(2:2,(0:1,1:1):1);\n");
+
+        circuits.connect_shortest(None);
+        let dendrogram = Dendrogram::new(&circuits);
+        assert_eq!(dendrogram.merges.last().map(|m| m.size), Some(4));
+        assert_eq!(dendrogram.newick(), "(3:7,(2:2,(0:1,1:1):1):5);\n");
+
+        // The longest connection made by hand first still merges last.
+        let mut circuits = CircuitMap::new(&boxes);
+        circuits.connect((2, 3));
+        circuits.connect_shortest(None);
+        assert_eq!(circuits.accepted()[0], ((2, 3), 49));
+        let newick = Dendrogram::new(&circuits).newick();
+        assert_eq!(newick, "(3:7,(2:2,(0:1,1:1):1):5);\n");
+    }
+}
@@ -0,0 +1,107 @@
+//! Writing the circuits out for Graphviz or other tooling.
+
+use std::{fmt::Write, str::FromStr};
+
+use aoc_common::json::Object;
+
+use crate::{CircuitMap, dendrogram::Dendrogram};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Export {
+    Dot,
+    Json,
+    /// The merges as text, see `Dendrogram::text`.
+    Dendrogram,
+    Newick,
+}
+
+impl FromStr for Export {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dot" => Ok(Self::Dot),
+            "json" => Ok(Self::Json),
+            "dendrogram" => Ok(Self::Dendrogram),
+            "newick" => Ok(Self::Newick),
+            _ => Err(format!("Invalid export format: {s}")),
+        }
+    }
+}
+
+impl Export {
+    pub fn write(self, circuits: &CircuitMap) -> String {
+        match self {
+            Self::Dot => dot(circuits),
+            Self::Json => json(circuits),
+            Self::Dendrogram => Dendrogram::new(circuits).text(),
+            Self::Newick => Dendrogram::new(circuits).newick(),
+        }
+    }
+}
+
+/// An undirected graph with a node per box, labelled with its position, and
+/// an edge per accepted connection, labelled with its squared distance.
+pub fn dot(circuits: &CircuitMap) -> String {
+    let mut dot = String::from("graph circuits {\n");
+    for (i, b) in circuits.boxes.iter().enumerate() {
+        let _ = writeln!(dot, "  {i} [label=\"{},{},{}\"];", b.x, b.y, b.z);
+    }
+    for ((from, to), distance_squared) in circuits.accepted() {
+        let _ = writeln!(dot, "  {from} -- {to} [label=\"{distance_squared}\"];");
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// One line with the `boxes` as `x`, `y`, `z` objects and the accepted
+/// `connections` between their indices, in the order they were made.
+pub fn json(circuits: &CircuitMap) -> String {
+    let boxes = circuits.boxes.iter().map(|b| {
+        Object::new()
+            .integer("x", b.x)
+            .integer("y", b.y)
+            .integer("z", b.z)
+    });
+    let connections = circuits
+        .accepted()
+        .iter()
+        .map(|&((from, to), distance_squared)| {
+            Object::new()
+                .number("from", from as u128)
+                .number("to", to as u128)
+                .number("distance_squared", distance_squared)
+        });
+    let json = Object::new()
+        .array("boxes", boxes)
+        .array("connections", connections)
+        .number("circuits", circuits.circuit_count() as u128);
+    format!("{json}\n")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CircuitMap, JunctionBox, export};
+
+    #[test]
+    fn test_export() {
+        let boxes = [(0, 0, 0), (3, 4, 0), (-10, 0, 0)].map(|(x, y, z)| JunctionBox { x, y, z });
+        let mut circuits = CircuitMap::new(&boxes);
+        circuits.connect_shortest(Some(1));
+        assert_eq!(circuits.accepted(), [((0, 1), 25)]);
+        assert_eq!(
+            export::dot(&circuits),
+            "graph circuits {\n  0 [label=\"0,0,0\"];\n  1 [label=\"3,4,0\"];\n  \
+             2 [label=\"-10,0,0\"];\n  0 -- 1 [label=\"25\"];\n}\n"
+        );
+        circuits.connect_shortest(None);
+        assert_eq!(circuits.accepted(), [((0, 1), 25), ((0, 2), 100)]);
+        assert_eq!(
+            export::json(&circuits),
+            "{\"boxes\":[{\"x\":0,\"y\":0,\"z\":0},{\"x\":3,\"y\":4,\"z\":0},\
+             {\"x\":-10,\"y\":0,\"z\":0}],\"connections\":[{\"from\":0,\"to\":1,\
+             \"distance_squared\":25},{\"from\":0,\"to\":2,\"distance_squared\":100}],\
+             \"circuits\":1}\n"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
+
+use crate::{Connection, JunctionBox, square_difference};
+
+/// A box at some squared distance from another, ordered by distance and
+/// then index so equal distances always come out the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Neighbour {
+    pub distance_squared: u128,
+    pub index: usize,
+}
+
+/// Junction boxes split on x, y and z in turn. The tree is implicit: the
+/// median of every slice of `order` is the node, the halves its subtrees.
+#[derive(Debug)]
+pub struct KdTree<'a> {
+    boxes: &'a [JunctionBox],
+    order: Vec<usize>,
+}
+
+fn coordinate(junction_box: &JunctionBox, axis: usize) -> i64 {
+    match axis {
+        0 => junction_box.x,
+        1 => junction_box.y,
+        _ => junction_box.z,
+    }
+}
+
+impl<'a> KdTree<'a> {
+    pub fn new(boxes: &'a [JunctionBox]) -> Self {
+        let mut order = (0..boxes.len()).collect::<Vec<_>>();
+        Self::build(boxes, &mut order, 0);
+        Self { boxes, order }
+    }
+
+    fn build(boxes: &[JunctionBox], slice: &mut [usize], axis: usize) {
+        if slice.len() <= 1 {
+            return;
+        }
+        let mid = slice.len() / 2;
+        slice.select_nth_unstable_by_key(mid, |&i| coordinate(&boxes[i], axis));
+        let (left, right) = slice.split_at_mut(mid);
+        Self::build(boxes, left, (axis + 1) % 3);
+        Self::build(boxes, &mut right[1..], (axis + 1) % 3);
+    }
+
+    /// The `k` boxes closest to box `of`, not counting itself, nearest first.
+    pub fn nearest(&self, of: usize, k: usize) -> Vec<Neighbour> {
+        let mut found = BinaryHeap::with_capacity(k + 1);
+        if k > 0 {
+            self.search(of, k, &self.order, 0, &mut found);
+        }
+        found.into_sorted_vec()
+    }
+
+    fn search(
+        &self,
+        of: usize,
+        k: usize,
+        slice: &[usize],
+        axis: usize,
+        found: &mut BinaryHeap<Neighbour>,
+    ) {
+        if slice.is_empty() {
+            return;
+        }
+        let mid = slice.len() / 2;
+        let node = slice[mid];
+        let target = &self.boxes[of];
+        if node != of {
+            found.push(Neighbour {
+                distance_squared: target.distance_squared(&self.boxes[node]),
+                index: node,
+            });
+            if found.len() > k {
+                found.pop();
+            }
+        }
+        let (target_coordinate, node_coordinate) = (
+            coordinate(target, axis),
+            coordinate(&self.boxes[node], axis),
+        );
+        let (near, far) = match target_coordinate < node_coordinate {
+            true => (&slice[..mid], &slice[mid + 1..]),
+            false => (&slice[mid + 1..], &slice[..mid]),
+        };
+        self.search(of, k, near, (axis + 1) % 3, found);
+        // Equal distances are searched too, ties are broken by index.
+        let plane = square_difference(target_coordinate, node_coordinate);
+        let worst = found.peek().map(|n| n.distance_squared);
+        if found.len() < k || worst.is_some_and(|worst| plane <= worst) {
+            self.search(of, k, far, (axis + 1) % 3, found);
+        }
+    }
+}
+
+/// The next neighbour of box `from`, ordered like the pair it makes so that
+/// equal distances come out by lower and then higher index.
+#[derive(Debug, PartialEq, Eq)]
+struct Queued {
+    neighbour: Neighbour,
+    from: usize,
+}
+
+impl Queued {
+    fn key(&self) -> (Neighbour, usize) {
+        let (low, high) = match self.from < self.neighbour.index {
+            true => (self.from, self.neighbour.index),
+            false => (self.neighbour.index, self.from),
+        };
+        let low = Neighbour {
+            distance_squared: self.neighbour.distance_squared,
+            index: low,
+        };
+        (low, high)
+    }
+}
+
+impl PartialOrd for Queued {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Queued {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key()
+            .cmp(&other.key())
+            .then(self.from.cmp(&other.from))
+    }
+}
+
+/// Every pair of boxes exactly once, shortest first, produced lazily.
+///
+/// Each box walks its own neighbours in order, fetched from the tree in
+/// growing batches, and a heap holds every box's next one. A pair is seen
+/// from both ends and only yielded from its lower index.
+pub struct Connections<'a> {
+    tree: KdTree<'a>,
+    neighbours: Vec<Vec<Neighbour>>,
+    next: Vec<usize>,
+    heap: BinaryHeap<Reverse<Queued>>,
+}
+
+impl<'a> Connections<'a> {
+    const FIRST_BATCH: usize = 8;
+
+    pub fn new(boxes: &'a [JunctionBox]) -> Self {
+        let mut connections = Self {
+            tree: KdTree::new(boxes),
+            neighbours: vec![Vec::new(); boxes.len()],
+            next: vec![0; boxes.len()],
+            heap: BinaryHeap::new(),
+        };
+        for junction_box in 0..boxes.len() {
+            connections.advance(junction_box);
+        }
+        connections
+    }
+
+    /// Queues the next neighbour of `junction_box`, if it has any left.
+    fn advance(&mut self, junction_box: usize) {
+        let next = self.next[junction_box];
+        let fetched = self.neighbours[junction_box].len();
+        if next == fetched && fetched < self.tree.boxes.len() - 1 {
+            let batch = (fetched * 2).max(Self::FIRST_BATCH);
+            self.neighbours[junction_box] = self.tree.nearest(junction_box, batch);
+        }
+        if let Some(&neighbour) = self.neighbours[junction_box].get(next) {
+            self.next[junction_box] += 1;
+            self.heap.push(Reverse(Queued {
+                neighbour,
+                from: junction_box,
+            }));
+        }
+    }
+}
+
+impl Iterator for Connections<'_> {
+    type Item = (Connection, u128);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Reverse(Queued { neighbour, from }) = self.heap.pop()?;
+            self.advance(from);
+            if from < neighbour.index {
+                return Some(((from, neighbour.index), neighbour.distance_squared));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{JunctionBox, kdtree::Connections};
+
+    #[test]
+    fn test_connections_in_order() {
+        // Small coordinates so plenty of distances tie.
+        let mut seed = 12345_u64;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % 8) as i64
+        };
+        let boxes = (0..60)
+            .map(|_| JunctionBox {
+                x: next(),
+                y: next(),
+                z: next(),
+            })
+            .collect::<Vec<_>>();
+
+        let mut expected = Vec::new();
+        for i in 0..boxes.len() {
+            for j in i + 1..boxes.len() {
+                expected.push((boxes[i].distance_squared(&boxes[j]), i, j));
+            }
+        }
+        expected.sort();
+        let lazy = Connections::new(&boxes)
+            .map(|((i, j), distance)| (distance, i, j))
+            .collect::<Vec<_>>();
+        assert_eq!(lazy, expected);
+    }
+}
@@ -0,0 +1,366 @@
+use aoc_common::{ParseError, Result, Solution, parse::lines};
+
+use crate::kdtree::Connections;
+
+pub mod dendrogram;
+pub mod export;
+pub mod kdtree;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    type Parsed = Playground;
+    type PartOne = usize;
+    type PartTwo = i128;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let boxes = parse_input(input)
+            .map_err(|e| e.with_hint("each line is a junction box position `X,Y,Z`"))?;
+        Ok(Playground {
+            boxes,
+            connections: Playground::CONNECTIONS,
+        })
+    }
+
+    fn part_one(playground: &Self::Parsed) -> Result<Self::PartOne> {
+        Ok(part_one(&playground.boxes, playground.connections))
+    }
+
+    fn part_two(playground: &Self::Parsed) -> Result<Self::PartTwo> {
+        part_two(&playground.boxes).ok_or("Need at least two junction boxes to connect".into())
+    }
+
+    fn configure(playground: &mut Self::Parsed, name: &str, value: &str) -> Result<()> {
+        match name {
+            "connections" => playground.connections = Playground::parse_connections(value)?,
+            _ => return Err(aoc_common::unknown_parameter::<Self>(name)),
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Playground {
+    pub boxes: Vec<JunctionBox>,
+    /// How many of the shortest connections part one makes.
+    pub connections: usize,
+}
+
+impl Playground {
+    /// Connections made for the real input, the example only makes 10.
+    pub const CONNECTIONS: usize = 1000;
+
+    /// A connection count given on the command line or in an example.
+    pub fn parse_connections(value: &str) -> Result<usize, String> {
+        match value.parse::<usize>() {
+            Ok(count) if count > 0 => Ok(count),
+            _ => Err(format!("Invalid connection count: {value}")),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<JunctionBox>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let mut coords = line.text.split(',');
+            let mut coordinate = |name: &str| -> Result<i64, ParseError> {
+                let token = line.expect(coords.next(), format!("{name} coordinate"))?;
+                let expected = "integer between -2^62 and 2^62";
+                let value = line.parse::<i64>(token, expected)?;
+                match value.unsigned_abs() <= JunctionBox::COORDINATE_LIMIT {
+                    true => Ok(value),
+                    false => Err(line.error(token, expected)),
+                }
+            };
+            let (x, y, z) = (coordinate("x")?, coordinate("y")?, coordinate("z")?);
+            line.expect_end(coords.next())?;
+            Ok(JunctionBox { x, y, z })
+        })
+        .collect()
+}
+
+pub fn part_one(boxes: &[JunctionBox], connections: usize) -> usize {
+    let mut circuit_map = CircuitMap::new(boxes);
+    circuit_map.connect_shortest(Some(connections));
+
+    let largest_3_circuits = {
+        let mut circuit_sizes = circuit_map.circuit_sizes();
+        circuit_sizes.sort_unstable();
+        circuit_sizes.into_iter().rev().take(3)
+    };
+
+    largest_3_circuits.into_iter().product()
+}
+
+/// Product of the x coordinates of the two boxes whose connection joins
+/// everything into one circuit, `None` if there aren't two boxes to connect.
+pub fn part_two(boxes: &[JunctionBox]) -> Option<i128> {
+    let mut circuit_map = CircuitMap::new(boxes);
+    circuit_map.connect_shortest(None);
+
+    let &(last, _) = circuit_map.accepted().last()?;
+    let boxes = circuit_map.boxes(last);
+    Some(i128::from(boxes.0.x) * i128::from(boxes.1.x))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JunctionBox {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// Square of the difference of two coordinates, exact for any `i64`s.
+pub fn square_difference(a: i64, b: i64) -> u128 {
+    let difference = (i128::from(a) - i128::from(b)).unsigned_abs();
+    difference * difference
+}
+
+impl JunctionBox {
+    /// Largest absolute coordinate `parse_input` accepts. Differences are
+    /// then at most 2^63 and three squares of them still fit a `u128`.
+    pub const COORDINATE_LIMIT: u64 = 1 << 62;
+
+    /// Exact for coordinates within `COORDINATE_LIMIT`.
+    pub fn distance_squared(&self, other: &Self) -> u128 {
+        square_difference(self.x, other.x)
+            + square_difference(self.y, other.y)
+            + square_difference(self.z, other.z)
+    }
+}
+
+pub type Connection = (usize, usize);
+
+/// Which junction boxes are wired into the same circuit, as a disjoint-set
+/// forest with union by size and path compression.
+#[derive(Debug)]
+pub struct CircuitMap<'a> {
+    pub boxes: &'a [JunctionBox],
+    /// Parent of every box, circuit roots are their own parent.
+    parents: Vec<usize>,
+    /// Number of boxes in the circuit of every root.
+    sizes: Vec<usize>,
+    circuit_count: usize,
+    /// Connections that merged two circuits, with their squared distances.
+    accepted: Vec<(Connection, u128)>,
+}
+
+impl<'a> CircuitMap<'a> {
+    pub fn new(boxes: &'a [JunctionBox]) -> Self {
+        Self {
+            boxes,
+            parents: (0..boxes.len()).collect(),
+            sizes: vec![1; boxes.len()],
+            circuit_count: boxes.len(),
+            accepted: Vec::new(),
+        }
+    }
+
+    /// Every possible connection with its squared distance, shortest first,
+    /// found lazily through a k-d tree rather than sorting all pairs.
+    pub fn connections(&self) -> Connections<'a> {
+        Connections::new(self.boxes)
+    }
+
+    pub fn connection_distance_squared(&self, connection: Connection) -> u128 {
+        self.boxes[connection.0].distance_squared(&self.boxes[connection.1])
+    }
+
+    /// The root box standing for the circuit `junction_box` is in.
+    pub fn find(&mut self, junction_box: usize) -> usize {
+        let mut root = junction_box;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut current = junction_box;
+        while self.parents[current] != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+        root
+    }
+
+    /// Wires the two boxes together, returning whether that merged two
+    /// circuits rather than adding a redundant wire inside one.
+    pub fn connect(&mut self, connection: Connection) -> bool {
+        let (left, right) = (self.find(connection.0), self.find(connection.1));
+        if left == right {
+            return false;
+        }
+        let (small, large) = match self.sizes[left] < self.sizes[right] {
+            true => (left, right),
+            false => (right, left),
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+        self.circuit_count -= 1;
+        let distance_squared = self.connection_distance_squared(connection);
+        self.accepted.push((connection, distance_squared));
+        true
+    }
+
+    /// Tries the shortest connections in order, stopping after `limit` of
+    /// them or once everything is a single circuit. Without a limit the
+    /// accepted connections form a minimum spanning tree.
+    pub fn connect_shortest(&mut self, limit: Option<usize>) {
+        let connections = self.connections().take(limit.unwrap_or(usize::MAX));
+        for (connection, _) in connections {
+            if self.is_complete() {
+                break;
+            }
+            self.connect(connection);
+        }
+    }
+
+    /// Single-linkage clustering by distance: makes every connection at most
+    /// `max_distance` long.
+    pub fn connect_within(&mut self, max_distance: f64) {
+        let max_distance_squared = max_distance * max_distance;
+        for (connection, distance_squared) in self.connections() {
+            if max_distance < 0.0 || distance_squared as f64 > max_distance_squared {
+                break;
+            }
+            if self.is_complete() {
+                break;
+            }
+            self.connect(connection);
+        }
+    }
+
+    /// Single-linkage clustering into a given number of circuits: makes the
+    /// shortest connections until only `circuits` are left, or fewer if
+    /// there were fewer boxes to begin with.
+    pub fn connect_until(&mut self, circuits: usize) {
+        for (connection, _) in self.connections() {
+            if self.circuit_count <= circuits.max(1) {
+                break;
+            }
+            self.connect(connection);
+        }
+    }
+
+    /// Boxes of every circuit in index order, circuits by their first box.
+    pub fn circuits(&mut self) -> Vec<Vec<usize>> {
+        let mut circuits = Vec::<Vec<usize>>::new();
+        let mut position = vec![None; self.parents.len()];
+        for junction_box in 0..self.parents.len() {
+            let root = self.find(junction_box);
+            let index = *position[root].get_or_insert(circuits.len());
+            if index == circuits.len() {
+                circuits.push(Vec::new());
+            }
+            circuits[index].push(junction_box);
+        }
+        circuits
+    }
+
+    /// Every connection that merged two circuits, in the order they were
+    /// made, with its squared distance. Together they form a spanning forest.
+    pub fn accepted(&self) -> &[(Connection, u128)] {
+        &self.accepted
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.circuit_count <= 1
+    }
+
+    pub fn circuit_count(&self) -> usize {
+        self.circuit_count
+    }
+
+    /// Sizes of all circuits, including single unconnected boxes.
+    pub fn circuit_sizes(&self) -> Vec<usize> {
+        (0..self.parents.len())
+            .filter(|&i| self.parents[i] == i)
+            .map(|root| self.sizes[root])
+            .collect()
+    }
+
+    /// Every box in the same circuit as `junction_box`, in index order.
+    pub fn members_of(&mut self, junction_box: usize) -> Vec<usize> {
+        let root = self.find(junction_box);
+        (0..self.parents.len())
+            .filter(|&i| self.find(i) == root)
+            .collect()
+    }
+
+    pub fn boxes(&self, connection: Connection) -> (&JunctionBox, &JunctionBox) {
+        (&self.boxes[connection.0], &self.boxes[connection.1])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Solution;
+
+    use crate::{CircuitMap, Day08, JunctionBox, parse_input, part_two};
+
+    #[test]
+    fn test_circuit_map() {
+        let boxes = vec![JunctionBox { x: 0, y: 0, z: 0 }; 5];
+        let mut circuits = CircuitMap::new(&boxes);
+        assert!(circuits.connect((0, 1)));
+        assert!(circuits.connect((3, 1)));
+        assert!(!circuits.connect((0, 3)));
+        assert_eq!(circuits.find(0), circuits.find(3));
+        assert_ne!(circuits.find(0), circuits.find(2));
+        assert_eq!(circuits.circuit_count(), 3);
+        assert_eq!(circuits.members_of(1), [0, 1, 3]);
+        let mut sizes = circuits.circuit_sizes();
+        sizes.sort();
+        assert_eq!(sizes, [1, 1, 3]);
+        assert!(circuits.connect((2, 4)) && circuits.connect((4, 0)));
+        assert!(circuits.is_complete());
+    }
+
+    #[test]
+    fn test_clustering() {
+        let boxes = [0, 1, 3, 10].map(|x| JunctionBox { x, y: 0, z: 0 });
+        let mut circuits = CircuitMap::new(&boxes);
+        circuits.connect_within(1.5);
+        assert_eq!(circuits.circuits(), [vec![0, 1], vec![2], vec![3]]);
+        circuits.connect_within(2.0);
+        assert_eq!(circuits.circuits(), [vec![0, 1, 2], vec![3]]);
+        let mut circuits = CircuitMap::new(&boxes);
+        circuits.connect_until(3);
+        assert_eq!(circuits.circuits(), [vec![0, 1], vec![2], vec![3]]);
+        circuits.connect_until(0);
+        assert_eq!(circuits.circuits(), [vec![0, 1, 2, 3]]);
+    }
+
+    #[test]
+    fn test_example() {
+        let mut playground = Day08::parse(include_str!("../../examples/example.input")).unwrap();
+        Day08::configure(&mut playground, "connections", "10").unwrap();
+        assert!(Day08::configure(&mut playground, "connections", "0").is_err());
+        assert!(Day08::configure(&mut playground, "steps", "10").is_err());
+        assert_eq!(Day08::part_one(&playground).unwrap(), 40);
+        assert_eq!(Day08::part_two(&playground).unwrap(), 25272);
+    }
+
+    #[test]
+    fn test_exact_coordinates() {
+        let boxes = parse_input("9007199254740993,0,0\n-9007199254740995,1,1\n").unwrap();
+        assert_eq!(part_two(&boxes), Some(-81129638414606717724586024108035));
+        assert_eq!(part_two(&boxes[..1]), None);
+        assert!(parse_input("1,2,3\n1.5,2,3\n").is_err());
+        assert!(parse_input("1,2\n").is_err());
+
+        let limit = 1_i64 << 62;
+        let input = format!(
+            "{limit},{limit},{limit}\n{},{},{}\n",
+            -limit, -limit, -limit
+        );
+        let boxes = parse_input(&input).unwrap();
+        assert_eq!(boxes[0].distance_squared(&boxes[1]), 3 << 126);
+        assert_eq!(part_two(&boxes), Some(-(1 << 124)));
+        let err = parse_input("9223372036854775807,0,0\n").unwrap_err();
+        assert_eq!(
+            (err.column, err.expected.as_str()),
+            (1, "integer between -2^62 and 2^62")
+        );
+        assert!(parse_input(&format!("0,{},0\n", -limit - 1)).is_err());
+    }
+}
@@ -0,0 +1,235 @@
+use std::{path::Path, process::ExitCode, str::FromStr};
+
+use crate::{
+    input::{self, Source},
+    json, solve_with, Answer, ParseError, Part, Report, Result, Solution,
+};
+
+/// Arguments every day binary accepts.
+pub const USAGE: &str =
+    "[INPUT | --input PATH | - | --inline TEXT] [--part N] [--color] [--format text|json]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The day's own description of each answer.
+    Text,
+    /// One JSON record per answer, with timings.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("Invalid format: {s}")),
+        }
+    }
+}
+
+/// Command line options shared by all day binaries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub parts: Vec<Part>,
+    pub color: bool,
+    pub format: Format,
+    /// `None` for the day's committed input.
+    pub input: Option<Source>,
+    pub help: bool,
+}
+
+impl Options {
+    /// Where to read the input from, the day's committed input by default.
+    pub fn source<S: Solution>(&self) -> Source {
+        self.input.clone().unwrap_or_else(|| {
+            Source::Path(input::default_path(&input::repo_root(), S::YEAR, S::DAY))
+        })
+    }
+
+    pub fn from_env() -> Result<Self> {
+        Self::from_args(std::env::args().skip(1))
+    }
+
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        Self::from_args_with(args, |_, _| Ok(false))
+    }
+
+    /// Like `from_args`, first offering every other flag to `flag` along
+    /// with the remaining arguments, for days taking options of their own.
+    /// `flag` returns whether it knew the flag.
+    pub fn from_args_with(
+        args: impl IntoIterator<Item = String>,
+        mut flag: impl FnMut(&str, &mut dyn Iterator<Item = String>) -> Result<bool>,
+    ) -> Result<Self> {
+        let mut parts = Vec::new();
+        let mut color = false;
+        let mut format = Format::Text;
+        let mut input = None;
+        let mut help = false;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => {
+                    let part = args.next().ok_or("Missing value for --part")?;
+                    parts.push(part.parse()?);
+                }
+                "--color" => color = true,
+                "--format" => {
+                    let value = args.next().ok_or("Missing value for --format")?;
+                    format = value.parse()?;
+                }
+                "--inline" => {
+                    let value = args.next().ok_or("Missing value for --inline")?;
+                    input = Some(Source::Inline(value));
+                }
+                "--input" => {
+                    let value = args.next().ok_or("Missing value for --input")?;
+                    input = Some(Source::from_arg(&value));
+                }
+                "-h" | "--help" => help = true,
+                _ if input.is_none() && (arg == "-" || !arg.starts_with('-')) => {
+                    input = Some(Source::from_arg(&arg));
+                }
+                _ if flag(&arg, &mut args)? => {}
+                _ => return Err(format!("Unexpected argument: {arg}").into()),
+            }
+        }
+        if parts.is_empty() {
+            parts.extend(Part::ALL);
+        }
+        Ok(Self {
+            parts,
+            color,
+            format,
+            input,
+            help,
+        })
+    }
+}
+
+/// Entry point of a day binary: solves the parts selected on the command line
+/// and hands every answer to `print`, reporting errors on stderr.
+pub fn run<S: Solution>(print: impl Fn(&Answer)) -> ExitCode {
+    run_with::<S>(Options::from_env(), "", |_| Ok(()), print)
+}
+
+/// Like `run`, for days with options of their own: `options` usually comes
+/// from `Options::from_args_with`, `usage` describes the extra flags and
+/// `adjust` applies them to the parsed input.
+pub fn run_with<S: Solution>(
+    options: Result<Options>,
+    usage: &str,
+    adjust: impl FnOnce(&mut S::Parsed) -> Result<()>,
+    print: impl Fn(&Answer),
+) -> ExitCode {
+    let program = std::env::args()
+        .next()
+        .and_then(|program| Some(Path::new(&program).file_name()?.to_str()?.to_string()))
+        .unwrap_or_else(|| format!("aoc{}-{:02}", S::YEAR, S::DAY));
+    let usage = format!("Usage: {program} {USAGE}{usage}");
+    let options = match options {
+        Ok(options) if options.help => {
+            println!("{usage}");
+            return ExitCode::SUCCESS;
+        }
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("error: {e}");
+            eprintln!("{usage}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = match options.source::<S>().load() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match solve_with::<S>(&input, &options.parts, adjust) {
+        Ok(report) => {
+            match options.format {
+                Format::Text => report.answers.iter().for_each(print),
+                Format::Json => print_json::<S>(&report),
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            match e.downcast_ref::<ParseError>() {
+                Some(e) => eprint!("{}", e.render(&input, options.color)),
+                None => eprintln!("error: {e}"),
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn print_json<S: Solution>(report: &Report) {
+    for answer in &report.answers {
+        let part = match answer.part {
+            Part::One => 1u8,
+            Part::Two => 2,
+        };
+        let record = json::Object::new()
+            .number("year", S::YEAR)
+            .number("day", S::DAY)
+            .number("part", part)
+            .string("answer", &answer.value)
+            .number("parse_ns", report.parse_elapsed.as_nanos())
+            .number("solve_ns", answer.elapsed.as_nanos());
+        println!("{record}");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        cli::{Format, Options},
+        input::Source,
+        Part,
+    };
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_options() {
+        let options = Options::from_args(args("--part 2 --format json --color -")).unwrap();
+        assert_eq!(options.parts, vec![Part::Two]);
+        assert_eq!(options.format, Format::Json);
+        assert!(options.color);
+        assert_eq!(options.input, Some(Source::Stdin));
+
+        let options = Options::from_args(args("")).unwrap();
+        assert_eq!(options.parts, Part::ALL.to_vec());
+        assert_eq!(options.format, Format::Text);
+        assert_eq!(options.input, None);
+
+        let options = Options::from_args(args("../input")).unwrap();
+        assert_eq!(options.input, Some(Source::Path("../input".into())));
+        assert!(Options::from_args(args("one two")).is_err());
+
+        assert!(Options::from_args(args("--format")).is_err());
+        assert!(Options::from_args(args("--bogus")).is_err());
+    }
+
+    #[test]
+    fn test_day_flags() {
+        let mut steps = None;
+        let options = Options::from_args_with(args("--steps 10 --input ex.txt"), |flag, args| {
+            match flag {
+                "--steps" => steps = args.next(),
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })
+        .unwrap();
+        assert_eq!(steps.as_deref(), Some("10"));
+        assert_eq!(options.input, Some(Source::Path("ex.txt".into())));
+        assert!(Options::from_args_with(args("--other 1"), |_, _| Ok(false)).is_err());
+        assert!(Options::from_args(args("--help")).unwrap().help);
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt::Write;
+
+use crate::ParseError;
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const CYAN: &str = "\x1b[1;36m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+struct Style {
+    color: bool,
+}
+
+impl Style {
+    fn paint(&self, code: &str, text: &str) -> String {
+        if self.color {
+            format!("{code}{text}{RESET}")
+        } else {
+            text.into()
+        }
+    }
+}
+
+/// Renders `error` rustc-style, quoting its line from `source` with a caret
+/// under the offending text. `color` adds ANSI escapes.
+pub fn render(error: &ParseError, source: &str, color: bool) -> String {
+    let style = Style { color };
+    let mut out = String::new();
+
+    let found = if error.found.is_empty() {
+        "end of line".into()
+    } else {
+        format!("'{}'", error.found)
+    };
+    let message = format!("expected {}, found {found}", error.expected);
+    let _ = writeln!(
+        out,
+        "{}{}",
+        style.paint(RED, "error"),
+        style.paint(BOLD, &format!(": {message}"))
+    );
+
+    let number = error.line.to_string();
+    let pad = " ".repeat(number.len());
+    let gutter = style.paint(BLUE, "|");
+    let _ = writeln!(
+        out,
+        "{pad}{} line {}, col {}",
+        style.paint(BLUE, "-->"),
+        error.line,
+        error.column
+    );
+
+    if let Some(text) = source.lines().nth(error.line - 1) {
+        let text = text.trim_end_matches('\r');
+        // Keep tabs so the caret lines up with the quoted text.
+        let indent = text
+            .chars()
+            .take(error.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        let carets = "^".repeat(error.found.chars().count().max(1));
+        let _ = writeln!(out, "{pad} {gutter}");
+        let _ = writeln!(out, "{} {gutter} {text}", style.paint(BLUE, &number));
+        let _ = writeln!(
+            out,
+            "{pad} {gutter} {indent}{}",
+            style.paint(RED, &format!("{carets} expected {}", error.expected))
+        );
+    }
+
+    if let Some(hint) = &error.hint {
+        let _ = writeln!(out, "{pad} {gutter}");
+        let _ = writeln!(out, "{pad} {} {hint}", style.paint(CYAN, "= hint:"));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ParseError;
+
+    #[test]
+    fn test_render_plain() {
+        let source = "move 1 from 2 to 1\nmove x from 1 to 3\n";
+        let error = ParseError::new(2, 6, "x", "number of crates")
+            .with_hint("moves look like `move 1 from 2 to 1`");
+        assert_eq!(
+            error.render(source, false),
+            "\
+error: expected number of crates, found 'x'
+ --> line 2, col 6
+  |
+2 | move x from 1 to 3
+  |      ^ expected number of crates
+  |
+  = hint: moves look like `move 1 from 2 to 1`
+"
+        );
+    }
+
+    #[test]
+    fn test_render_end_of_line() {
+        let error = ParseError::new(1, 2, "", "second column");
+        assert_eq!(
+            error.render("A\n", false),
+            "\
+error: expected second column, found end of line
+ --> line 1, col 2
+  |
+1 | A
+  |  ^ expected second column
+"
+        );
+    }
+}
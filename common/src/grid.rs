@@ -0,0 +1,302 @@
+//! A rectangular grid of cells addressed by `(x, y)`, with `(0, 0)` in the
+//! top left corner and `y` growing downwards, like puzzle inputs read.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{parse::lines, ParseError};
+
+pub type Position = (usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [Self::Up, Self::Down, Self::Left, Self::Right];
+    pub const ALL: [Direction; 8] = [
+        Self::Up,
+        Self::Down,
+        Self::Left,
+        Self::Right,
+        Self::UpLeft,
+        Self::UpRight,
+        Self::DownLeft,
+        Self::DownRight,
+    ];
+
+    /// Change of `(x, y)` when taking one step.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Self::Up => (0, -1),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+            Self::UpLeft => (-1, -1),
+            Self::UpRight => (1, -1),
+            Self::DownLeft => (-1, 1),
+            Self::DownRight => (1, 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses one cell per character, failing with `expected` where `cell`
+    /// returns `None`. Rows must all be as long as the first one.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in lines(input) {
+            let mut row = 0;
+            for (i, c) in line.text.char_indices() {
+                let value =
+                    cell(c).ok_or_else(|| line.error(&line.text[i..i + c.len_utf8()], expected))?;
+                cells.push(value);
+                row += 1;
+            }
+            match width {
+                None if row == 0 => return Err(line.end_error("row of cells")),
+                None => width = Some(row),
+                Some(width) if width != row => {
+                    return Err(line.error(line.text, format!("row of {width} cells")))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        let width = width.ok_or_else(|| ParseError::new(1, 1, "", "row of cells"))?;
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, (x, y): Position) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(x + y * self.width)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    /// The neighbouring position in `direction`, if it is inside the grid.
+    pub fn step(&self, (x, y): Position, direction: Direction) -> Option<Position> {
+        let (dx, dy) = direction.offset();
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.index_of(position).map(|_| position)
+    }
+
+    /// Positions row by row, the order cells are stored and displayed in.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Panics if `y` is outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            y < self.height,
+            "row {y} outside a grid of height {}",
+            self.height
+        );
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Panics if `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} outside a grid of width {}",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Cells met walking from `from` in `direction` up to the edge, not
+    /// including `from` itself.
+    pub fn ray(
+        &self,
+        from: Position,
+        direction: Direction,
+    ) -> impl Iterator<Item = (Position, &T)> {
+        let mut position = from;
+        std::iter::from_fn(move || {
+            position = self.step(position, direction)?;
+            Some((position, &self[position]))
+        })
+    }
+
+    /// Cells next to `position` in the given directions, usually
+    /// `Direction::ORTHOGONAL` or `Direction::ALL`.
+    pub fn neighbours<'a>(
+        &'a self,
+        position: Position,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Position, &'a T)> {
+        directions.iter().filter_map(move |&direction| {
+            let neighbour = self.step(position, direction)?;
+            Some((neighbour, &self[neighbour]))
+        })
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(&mut f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height;
+        Self::from_fn(height, self.width, |(x, y)| {
+            self[(y, height - 1 - x)].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        let width = self.width;
+        Self::from_fn(self.height, width, |(x, y)| {
+            self[(width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} outside a {width}x{height} grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} outside a {width}x{height} grid"))
+    }
+}
+
+/// One line per row, cells written next to each other.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::grid::{Direction, Grid};
+
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef\n", "letter", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_get() {
+        let grid = letters();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 0)), Some(&'c'));
+        assert_eq!(grid.get((0, 1)), Some(&'d'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+
+        let err = Grid::parse("12\n1x\n", "digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "x"));
+        let err = Grid::parse("12\n123\n", "digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "row of 2 cells"));
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = letters();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        let ray = grid.ray((0, 0), Direction::Right).map(|(_, c)| *c);
+        assert_eq!(ray.collect::<String>(), "bc");
+        let ray = grid.ray((2, 1), Direction::UpLeft).map(|(p, _)| p);
+        assert_eq!(ray.collect::<Vec<_>>(), [(1, 0)]);
+        let around = grid.neighbours((1, 0), &Direction::ALL).map(|(_, c)| *c);
+        assert_eq!(around.collect::<String>(), "eacdf");
+    }
+
+    #[test]
+    fn test_transform_and_display() {
+        let grid = letters();
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+    }
+}
@@ -0,0 +1,97 @@
+use std::{
+    fs,
+    io::{stdin, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::Result;
+
+/// Where a day reads its puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    Path(PathBuf),
+    Inline(String),
+}
+
+impl Source {
+    /// `-` means stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            path => Self::Path(path.into()),
+        }
+    }
+
+    /// Reads the whole input, see `normalize`.
+    pub fn load(&self) -> Result<String> {
+        let input = match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                stdin().read_to_string(&mut input)?;
+                input
+            }
+            Self::Path(path) => fs::read_to_string(path)
+                .map_err(|e| format!("Can't read input {}: {e}", path.display()))?,
+            Self::Inline(input) => input.clone(),
+        };
+        Ok(normalize(&input))
+    }
+}
+
+/// Converts CRLF line endings to LF and ends non-empty input with exactly one
+/// newline. Other whitespace is kept, some drawings depend on it.
+pub fn normalize(input: &str) -> String {
+    let mut input = input.replace("\r\n", "\n");
+    input.truncate(input.trim_end_matches('\n').len());
+    if !input.is_empty() {
+        input.push('\n');
+    }
+    input
+}
+
+pub fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Common crate lives inside the repository")
+        .to_path_buf()
+}
+
+/// Directory shared by all of a day's solutions, holding its input.
+pub fn day_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(format!("{year}/{day:02}"))
+}
+
+/// The committed input: `input.txt` up to 2022, `input` since.
+pub fn default_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    let dir = day_dir(root, year, day);
+    if year <= 2022 {
+        dir.join("input.txt")
+    } else {
+        dir.join("input")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use crate::input::{default_path, normalize};
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("a\r\nb \r\n\r\n\n"), "a\nb \n");
+        assert_eq!(normalize("    [D]    \n 1 "), "    [D]    \n 1 \n");
+        assert_eq!(normalize("\n\n"), "");
+    }
+
+    #[test]
+    fn test_default_path() {
+        let root = Path::new("/aoc");
+        assert_eq!(
+            default_path(root, 2022, 5),
+            Path::new("/aoc/2022/05/input.txt")
+        );
+        assert_eq!(default_path(root, 2025, 8), Path::new("/aoc/2025/08/input"));
+    }
+}
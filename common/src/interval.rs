@@ -0,0 +1,275 @@
+//! Half-open intervals and sets of them, the Rust side of the 2025/05 Gleam
+//! `range` and `range_set` modules.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    iter::Sum,
+    ops::Sub,
+};
+
+/// The values `start <= x < end`, never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Copy + Ord> Interval<T> {
+    /// `None` if the interval would be empty.
+    pub fn new(start: T, end: T) -> Option<Self> {
+        (start < end).then_some(Self { start, end })
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// First value after the interval.
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether every value of `other` is in `self`.
+    pub fn covers(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether the two share at least one value.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Whether the two overlap or are adjacent, so their union is one interval.
+    pub fn touches(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The smallest interval covering both, `None` unless they touch.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        self.touches(other).then(|| Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    /// What's left of `self` before and after `other`.
+    pub fn difference(&self, other: &Self) -> (Option<Self>, Option<Self>) {
+        if !self.overlaps(other) {
+            return (Some(*self), None);
+        }
+        (
+            Self::new(self.start, other.start),
+            Self::new(other.end, self.end),
+        )
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> Interval<T> {
+    pub fn len(&self) -> T {
+        self.end - self.start
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// Disjoint intervals, kept merged so no two of them touch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    /// End of every interval by its start.
+    intervals: BTreeMap<T, T>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The merged intervals in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals
+            .iter()
+            .map(|(&start, &end)| Interval { start, end })
+    }
+
+    /// Intervals that overlap or touch `interval`.
+    fn touching(&self, interval: Interval<T>) -> Vec<Interval<T>> {
+        let before = self
+            .intervals
+            .range(..interval.start)
+            .next_back()
+            .map(|(&start, &end)| Interval { start, end })
+            .filter(|previous| previous.touches(&interval));
+        let after = self
+            .intervals
+            .range(interval.start..=interval.end)
+            .map(|(&start, &end)| Interval { start, end });
+        before.into_iter().chain(after).collect()
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut merged = interval;
+        for touching in self.touching(interval) {
+            self.intervals.remove(&touching.start);
+            merged = merged.union(&touching).unwrap_or(merged);
+        }
+        self.intervals.insert(merged.start, merged.end);
+    }
+
+    pub fn remove(&mut self, interval: Interval<T>) {
+        for touching in self.touching(interval) {
+            if !touching.overlaps(&interval) {
+                continue;
+            }
+            self.intervals.remove(&touching.start);
+            let (before, after) = touching.difference(&interval);
+            for rest in before.into_iter().chain(after) {
+                self.intervals.insert(rest.start, rest.end);
+            }
+        }
+    }
+
+    /// The interval holding `value`, if any.
+    pub fn interval_containing(&self, value: T) -> Option<Interval<T>> {
+        let (&start, &end) = self.intervals.range(..=value).next_back()?;
+        let interval = Interval { start, end };
+        interval.contains(value).then_some(interval)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.interval_containing(value).is_some()
+    }
+
+    /// Whether every value of `interval` is in the set.
+    pub fn covers(&self, interval: &Interval<T>) -> bool {
+        self.interval_containing(interval.start)
+            .is_some_and(|containing| containing.covers(interval))
+    }
+
+    /// Whether any value of `interval` is in the set.
+    pub fn overlaps(&self, interval: &Interval<T>) -> bool {
+        self.touching(*interval)
+            .iter()
+            .any(|touching| touching.overlaps(interval))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        other.iter().for_each(|interval| union.insert(interval));
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Self::new();
+        for interval in self.iter() {
+            for touching in other.touching(interval) {
+                if let Some(common) = interval.intersection(&touching) {
+                    intersection.insert(common);
+                }
+            }
+        }
+        intersection
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        other
+            .iter()
+            .for_each(|interval| difference.remove(interval));
+        difference
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Sum> IntervalSet<T> {
+    /// Number of values covered.
+    pub fn len(&self) -> T {
+        self.iter().map(|interval| interval.len()).sum()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        let mut set = Self::new();
+        intervals
+            .into_iter()
+            .for_each(|interval| set.insert(interval));
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::interval::{Interval, IntervalSet};
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet<i64> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end).unwrap())
+            .collect()
+    }
+
+    fn bounds(set: &IntervalSet<i64>) -> Vec<(i64, i64)> {
+        set.iter().map(|i| (i.start(), i.end())).collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::new(3, 6).unwrap();
+        let b = Interval::new(5, 9).unwrap();
+        assert_eq!(Interval::new(4, 4), None);
+        assert!(a.contains(5) && !a.contains(6));
+        assert!(a.overlaps(&b) && !a.covers(&b));
+        assert_eq!(a.intersection(&b), Interval::new(5, 6));
+        assert_eq!(a.union(&b), Interval::new(3, 9));
+        assert_eq!(b.difference(&a), (None, Interval::new(6, 9)));
+        assert_eq!(a.len(), 3);
+    }
+
+    #[test]
+    fn test_set_merges() {
+        // The 2025/05 example ranges, made half-open.
+        let fresh = set(&[(3, 6), (10, 15), (16, 21), (12, 19)]);
+        assert_eq!(bounds(&fresh), [(3, 6), (10, 21)]);
+        assert_eq!(fresh.len(), 14);
+        assert!(!fresh.contains(1) && fresh.contains(5) && !fresh.contains(8));
+        assert!(fresh.contains(11) && fresh.contains(17) && !fresh.contains(32));
+        assert_eq!(bounds(&set(&[(1, 3), (3, 5)])), [(1, 5)]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(bounds(&a.union(&b)), [(0, 30)]);
+        assert_eq!(bounds(&a.intersection(&b)), [(5, 10), (20, 25)]);
+        assert_eq!(bounds(&a.difference(&b)), [(0, 5), (25, 30)]);
+        assert!(a.covers(&Interval::new(21, 30).unwrap()));
+        assert!(!a.covers(&Interval::new(9, 21).unwrap()));
+        assert!(a.overlaps(&Interval::new(9, 21).unwrap()));
+        assert!(!a.overlaps(&Interval::new(10, 20).unwrap()));
+    }
+}
@@ -0,0 +1,185 @@
+//! Just enough JSON for machine-readable reports: writing single-line
+//! objects, possibly nested, and reading flat ones back.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Write},
+    iter::Peekable,
+    str::Chars,
+};
+
+/// Formats `value` as a JSON string literal.
+pub fn quote(value: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", c as u32);
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Builds a single-line JSON object field by field.
+#[derive(Debug, Default)]
+pub struct Object {
+    fields: Vec<(String, String)>,
+}
+
+impl Object {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn string(mut self, key: &str, value: &str) -> Self {
+        self.fields.push((key.into(), quote(value)));
+        self
+    }
+
+    pub fn number(mut self, key: &str, value: impl Into<u128>) -> Self {
+        self.fields.push((key.into(), value.into().to_string()));
+        self
+    }
+
+    pub fn integer(mut self, key: &str, value: impl Into<i128>) -> Self {
+        self.fields.push((key.into(), value.into().to_string()));
+        self
+    }
+
+    /// An array of values that are JSON already, like other objects.
+    pub fn array<T: Display>(mut self, key: &str, values: impl IntoIterator<Item = T>) -> Self {
+        let values = values.into_iter().map(|value| value.to_string());
+        let values = values.collect::<Vec<_>>().join(",");
+        self.fields.push((key.into(), format!("[{values}]")));
+        self
+    }
+}
+
+impl std::fmt::Display for Object {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}:{value}", quote(key))?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Parses an object whose values are all strings, numbers or other bare
+/// literals, returning each value as text with strings unquoted.
+pub fn parse_flat(text: &str) -> Option<BTreeMap<String, String>> {
+    let mut chars = text.trim().chars().peekable();
+    let mut fields = BTreeMap::new();
+    (chars.next()? == '{').then_some(())?;
+    skip_whitespace(&mut chars);
+    if chars.peek() == Some(&'}') {
+        chars.next();
+        return chars.next().is_none().then_some(fields);
+    }
+    loop {
+        skip_whitespace(&mut chars);
+        (chars.next()? == '"').then_some(())?;
+        let key = unquote(&mut chars)?;
+        skip_whitespace(&mut chars);
+        (chars.next()? == ':').then_some(())?;
+        skip_whitespace(&mut chars);
+        let value = match chars.peek()? {
+            '"' => {
+                chars.next();
+                unquote(&mut chars)?
+            }
+            _ => {
+                let mut value = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c == ',' || c == '}' || c.is_whitespace() {
+                        break;
+                    }
+                    value.push(c);
+                    chars.next();
+                }
+                value
+            }
+        };
+        fields.insert(key, value);
+        skip_whitespace(&mut chars);
+        match chars.next()? {
+            ',' => continue,
+            '}' => return chars.next().is_none().then_some(fields),
+            _ => return None,
+        }
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+/// Reads a string literal up to and including its closing quote.
+fn unquote(chars: &mut Peekable<Chars>) -> Option<String> {
+    let mut value = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(value),
+            '\\' => value.push(match chars.next()? {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'u' => {
+                    let code = (0..4).map(|_| chars.next()).collect::<Option<String>>()?;
+                    char::from_u32(u32::from_str_radix(&code, 16).ok()?)?
+                }
+                c => c,
+            }),
+            c => value.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::json::{parse_flat, quote, Object};
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("a \"b\"\n\u{1}"), "\"a \\\"b\\\"\\n\\u0001\"");
+    }
+
+    #[test]
+    fn test_object() {
+        let object = Object::new()
+            .number("year", 2022u16)
+            .string("answer", "CMZ");
+        assert_eq!(object.to_string(), "{\"year\":2022,\"answer\":\"CMZ\"}");
+        let points = [-1i64, 2].map(|x| Object::new().integer("x", x));
+        let object = Object::new()
+            .array("points", points)
+            .array("empty", [0u8; 0]);
+        assert_eq!(
+            object.to_string(),
+            "{\"points\":[{\"x\":-1},{\"x\":2}],\"empty\":[]}"
+        );
+    }
+
+    #[test]
+    fn test_parse_flat() {
+        let object = Object::new()
+            .number("part", 1u8)
+            .string("answer", "a \"b\"\n\u{1}");
+        let fields = parse_flat(&object.to_string()).unwrap();
+        assert_eq!(fields["part"], "1");
+        assert_eq!(fields["answer"], "a \"b\"\n\u{1}");
+        assert_eq!(parse_flat("{ \"part one\": 6 }").unwrap()["part one"], "6");
+        assert_eq!(parse_flat("{\"a\":1"), None);
+    }
+}
@@ -0,0 +1,18 @@
+pub mod cli;
+pub mod diagnostic;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod json;
+pub mod parse;
+mod part;
+mod solution;
+pub mod toml;
+
+use std::error::Error;
+
+pub use parse::{Line, ParseError};
+pub use part::Part;
+pub use solution::{solve, solve_with, unknown_parameter, Answer, Report, Solution};
+
+pub type Result<T, E = Box<dyn Error>> = std::result::Result<T, E>;
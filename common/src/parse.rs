@@ -0,0 +1,162 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Input rejected by a parser, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending text, empty when the line ended too early.
+    pub found: String,
+    pub expected: String,
+    /// What valid input looks like, shown below rendered diagnostics.
+    pub hint: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        found: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            found: found.into(),
+            expected: expected.into(),
+            hint: None,
+        }
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Source snippet with a caret under the offending text, see `diagnostic::render`.
+    pub fn render(&self, source: &str, color: bool) -> String {
+        crate::diagnostic::render(self, source, color)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, col {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "'{}'", self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of input that knows its position, for building `ParseError`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// 1-based column of `token`, which must be a slice of this line's text.
+    pub fn column_of(&self, token: &str) -> usize {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|offset| *offset <= self.text.len())
+            .expect("Token is a slice of the line");
+        self.text[..offset].chars().count() + 1
+    }
+
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column_of(token), token, expected)
+    }
+
+    /// Error for a line that ended before `expected` was found.
+    pub fn end_error(&self, expected: impl Into<String>) -> ParseError {
+        let column = self.text.chars().count() + 1;
+        ParseError::new(self.number, column, "", expected)
+    }
+
+    /// Unwraps a token taken from this line, reporting its absence.
+    pub fn expect(
+        &self,
+        token: Option<&'a str>,
+        expected: impl Into<String>,
+    ) -> Result<&'a str, ParseError> {
+        token.ok_or_else(|| self.end_error(expected))
+    }
+
+    /// Like `expect`, but the token must also equal `literal`.
+    pub fn expect_literal(&self, token: Option<&'a str>, literal: &str) -> Result<(), ParseError> {
+        let expected = format!("'{literal}'");
+        match self.expect(token, &expected)? {
+            t if t == literal => Ok(()),
+            t => Err(self.error(t, expected)),
+        }
+    }
+
+    /// Requires that no tokens are left on the line.
+    pub fn expect_end(&self, token: Option<&'a str>) -> Result<(), ParseError> {
+        match token {
+            None => Ok(()),
+            Some(t) => Err(self.error(t, "end of line")),
+        }
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = &'a str> {
+        self.text.split_ascii_whitespace()
+    }
+}
+
+/// The lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parse::{lines, ParseError};
+
+    #[test]
+    fn test_error_position() {
+        let input = "move 1 from 2 to 3\nmove x from 1 to 2\n";
+        let line = lines(input).nth(1).unwrap();
+        let token = line.words().nth(1).unwrap();
+        let err = line.parse::<u32>(token, "number").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 6, "x", "number"));
+        assert_eq!(err.to_string(), "line 2, col 6: expected number, found 'x'");
+    }
+
+    #[test]
+    fn test_missing_token() {
+        let line = lines("A").next().unwrap();
+        let mut words = line.words();
+        words.next();
+        let err = line.expect(words.next(), "second word").unwrap_err();
+        assert_eq!(err.column, 2);
+        assert_eq!(
+            err.to_string(),
+            "line 1, col 2: expected second word, found end of line"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::{ParseError, Part, Result};
+
+/// A day's puzzle, split into parsing the input and solving each part.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Parsed;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+    fn part_one(parsed: &Self::Parsed) -> Result<Self::PartOne>;
+    fn part_two(parsed: &Self::Parsed) -> Result<Self::PartTwo>;
+
+    /// Sets a parameter that differs between the puzzle's examples and the
+    /// real input, like how many steps to simulate. Days without any reject
+    /// every name.
+    fn configure(parsed: &mut Self::Parsed, name: &str, value: &str) -> Result<()> {
+        let _ = (parsed, value);
+        Err(unknown_parameter::<Self>(name))
+    }
+}
+
+/// The error for a parameter `S` doesn't have, for `configure` to return
+/// after matching the names it does.
+pub fn unknown_parameter<S: Solution + ?Sized>(name: &str) -> Box<dyn Error> {
+    format!("{} day {} has no parameter {name}", S::YEAR, S::DAY).into()
+}
+
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub parse_elapsed: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// Parses `input` once and solves the requested parts, timing every stage.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Report> {
+    solve_with::<S>(input, parts, |_| Ok(()))
+}
+
+/// Like `solve`, with `adjust` changing the parsed input before any part is
+/// solved, for puzzles whose parameters differ between example and input.
+pub fn solve_with<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    adjust: impl FnOnce(&mut S::Parsed) -> Result<()>,
+) -> Result<Report> {
+    let start = Instant::now();
+    let mut parsed = S::parse(input)?;
+    adjust(&mut parsed)?;
+    let parse_elapsed = start.elapsed();
+
+    let mut answers = Vec::new();
+    for &part in parts {
+        let start = Instant::now();
+        let value = match part {
+            Part::One => S::part_one(&parsed)?.to_string(),
+            Part::Two => S::part_two(&parsed)?.to_string(),
+        };
+        answers.push(Answer {
+            part,
+            value,
+            elapsed: start.elapsed(),
+        });
+    }
+
+    Ok(Report {
+        parse_elapsed,
+        answers,
+    })
+}
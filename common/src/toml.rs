@@ -0,0 +1,165 @@
+//! The small subset of TOML used by the repository's data files: `[table]`
+//! headers and `key = value` pairs holding strings or bare values.
+
+use std::collections::BTreeMap;
+
+use crate::{parse::lines, ParseError};
+
+pub type Table = BTreeMap<String, String>;
+
+/// Tables by name, keys before the first header go in the `""` table.
+pub type Tables = BTreeMap<String, Table>;
+
+pub fn parse(input: &str) -> Result<Tables, ParseError> {
+    let mut tables = Tables::new();
+    let mut current = String::new();
+    for line in lines(input) {
+        let text = line.text.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+        if let Some(header) = text.strip_prefix('[') {
+            let (name, rest) =
+                key(header.trim_start()).ok_or_else(|| line.error(text, "table header [name]"))?;
+            if rest.trim() != "]" {
+                return Err(line.error(text, "table header [name]"));
+            }
+            current = name;
+            tables.entry(current.clone()).or_default();
+            continue;
+        }
+        let (key, rest) = key(text).ok_or_else(|| line.error(text, "key = value"))?;
+        let value = rest
+            .trim_start()
+            .strip_prefix('=')
+            .ok_or_else(|| line.error(text, "key = value"))?
+            .trim();
+        if key.is_empty() {
+            return Err(line.error(text, "key = value"));
+        }
+        let value = match value.strip_prefix('"') {
+            Some(quoted) => {
+                let (value, rest) =
+                    unquote(quoted).ok_or_else(|| line.error(value, "quoted string"))?;
+                let rest = rest.trim();
+                if !(rest.is_empty() || rest.starts_with('#')) {
+                    return Err(line.error(rest, "end of line or # comment"));
+                }
+                value
+            }
+            None => value.split('#').next().unwrap_or("").trim().into(),
+        };
+        tables
+            .entry(current.clone())
+            .or_default()
+            .insert(key, value);
+    }
+    Ok(tables)
+}
+
+/// A quoted or bare key or table name at the start of `text`, and the text
+/// after it.
+fn key(text: &str) -> Option<(String, &str)> {
+    if let Some(quoted) = text.strip_prefix('"') {
+        return unquote(quoted);
+    }
+    let end = text
+        .find(|c: char| !(c.is_ascii_alphanumeric() || "_-./".contains(c)))
+        .unwrap_or(text.len());
+    Some((text[..end].into(), &text[end..]))
+}
+
+/// Reads a basic string up to its closing quote, returning it unescaped and
+/// the text after it.
+fn unquote(quoted: &str) -> Option<(String, &str)> {
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+    loop {
+        match chars.next()? {
+            '"' => return Some((value, chars.as_str())),
+            '\\' => value.push(match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                c => c,
+            }),
+            c => value.push(c),
+        }
+    }
+}
+
+/// Formats `value` as a TOML basic string.
+pub fn quote(value: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Formats `tables` so that `parse` reads them back, quoting every key and
+/// value.
+pub fn to_string(tables: &Tables) -> String {
+    let mut text = String::new();
+    // The root table sorts first, so its keys come before any header.
+    for (name, table) in tables {
+        if !name.is_empty() {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&format!("[{}]\n", quote(name)));
+        }
+        for (key, value) in table {
+            text.push_str(&format!("{} = {}\n", quote(key), quote(value)));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use crate::toml::{parse, quote, to_string};
+
+    #[test]
+    fn test_parse() {
+        let tables = parse(
+            "# answers\nroot = 1\n\n[01]\npart_one = 72602 # comment\npart_two = \"a \\\"b\\\"\"\n",
+        )
+        .unwrap();
+        assert_eq!(tables[""]["root"], "1");
+        assert_eq!(tables["01"]["part_one"], "72602");
+        assert_eq!(tables["01"]["part_two"], "a \"b\"");
+    }
+
+    #[test]
+    fn test_quote_roundtrip() {
+        let value = "tab\there \"quoted\" back\\slash";
+        let tables = parse(&format!("key = {}", quote(value))).unwrap();
+        assert_eq!(tables[""]["key"], value);
+    }
+
+    #[test]
+    fn test_to_string_roundtrip() {
+        let mut tables = parse("wait = 5\n[\"2022/01\"]\n\"123\" = \"too high\"\n").unwrap();
+        let answers = tables.get_mut("2022/01").unwrap();
+        for answer in ["A=B", "say \"hi\"", "back\\slash\\", "[x] = \"y\""] {
+            answers.insert(answer.into(), "wrong".into());
+        }
+        tables.insert("a \"b\" ]".into(), Default::default());
+        assert_eq!(parse(&to_string(&tables)).unwrap(), tables);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("[01]\npart_one\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("[01]\npart_one = \"a\" # ok\npart_two = \"b\" junk\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 16));
+    }
+}
@@ -0,0 +1,43 @@
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{toml, Part, Result};
+
+/// Known-good answers of one year, stored in `YEAR/answers.toml`.
+pub struct Answers {
+    tables: toml::Tables,
+}
+
+impl Answers {
+    pub fn path(root: &Path, year: u16) -> PathBuf {
+        root.join(year.to_string()).join("answers.toml")
+    }
+
+    pub fn load(root: &Path, year: u16) -> Result<Self> {
+        let path = Self::path(root, year);
+        let tables = match fs::read_to_string(&path) {
+            Ok(text) => toml::parse(&text)
+                .map_err(|e| format!("{}: {}", path.display(), e.render(&text, false)))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => toml::Tables::new(),
+            Err(e) => return Err(format!("Can't read {}: {e}", path.display()).into()),
+        };
+        Ok(Self { tables })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.tables
+            .get(&format!("{day:02}"))?
+            .get(part_key(part))
+            .map(String::as_str)
+    }
+}
+
+pub fn part_key(part: Part) -> &'static str {
+    match part {
+        Part::One => "part_one",
+        Part::Two => "part_two",
+    }
+}
@@ -0,0 +1,202 @@
+use std::{fs, path::PathBuf, time::Duration};
+
+use aoc_common::{toml, Part, Result};
+
+use crate::{
+    answers::part_key,
+    days::{repo_root, select, Day},
+    table::{self, Table},
+};
+
+const USAGE: &str = "\
+Usage: aoc bench [YEAR [DAY]] [--runs N] [--baseline FILE] [--save FILE] [--threshold PCT]";
+
+/// Summary of repeated timings of one stage.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Panics if `samples` is empty.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = match n % 2 {
+            1 => sorted[n / 2],
+            _ => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        };
+        Self {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / n as u32,
+            max: sorted[n - 1],
+        }
+    }
+}
+
+/// Timings of one day: parsing first, then each part.
+struct Timings {
+    label: String,
+    stages: Vec<(&'static str, Stats)>,
+}
+
+fn stage_key(stage: &str) -> String {
+    format!("{stage}_ns")
+}
+
+/// Runs every selected day `--runs` times and prints per-stage statistics.
+/// With `--baseline`, medians slower than the baseline by more than
+/// `--threshold` percent are flagged and make the command fail.
+pub fn bench(args: &[String]) -> Result<bool> {
+    let mut positional = Vec::new();
+    let mut runs = 10;
+    let mut baseline = None;
+    let mut save = None;
+    let mut threshold = 10.0;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or(format!("Missing value for {arg}\n{USAGE}"))
+        };
+        match arg.as_str() {
+            "--runs" => runs = value()?.parse::<u32>()?,
+            "--baseline" => baseline = Some(PathBuf::from(value()?)),
+            "--save" => save = Some(PathBuf::from(value()?)),
+            "--threshold" => threshold = value()?.parse::<f64>()?,
+            _ => positional.push(arg.clone()),
+        }
+    }
+    if runs == 0 {
+        return Err("--runs must be at least 1".into());
+    }
+    let selected = select(&positional).map_err(|e| format!("{e}\n{USAGE}"))?;
+    let baseline = match baseline {
+        Some(path) => {
+            let text = fs::read_to_string(&path)
+                .map_err(|e| format!("Can't read {}: {e}", path.display()))?;
+            Some(
+                toml::parse(&text)
+                    .map_err(|e| format!("{}: {}", path.display(), e.render(&text, false)))?,
+            )
+        }
+        None => None,
+    };
+
+    let root = repo_root();
+    let mut results = Vec::new();
+    for day in &selected {
+        let input = day.load_input(&root)?;
+        results.push(measure(day, &input, runs)?);
+    }
+
+    let mut header = vec!["day", "stage", "min", "median", "mean", "max"];
+    if baseline.is_some() {
+        header.extend(["baseline", "change"]);
+    }
+    let mut table = Table::new(&header);
+    let mut regressions = 0;
+    for timings in &results {
+        for (stage, stats) in &timings.stages {
+            let mut row = vec![
+                timings.label.clone(),
+                stage.to_string(),
+                table::duration(stats.min),
+                table::duration(stats.median),
+                table::duration(stats.mean),
+                table::duration(stats.max),
+            ];
+            let previous = baseline
+                .as_ref()
+                .and_then(|tables| tables.get(&timings.label)?.get(&stage_key(stage)));
+            match previous {
+                Some(previous) => {
+                    let previous = Duration::from_nanos(previous.parse()?);
+                    let change =
+                        (stats.median.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+                    let flag = if change > threshold {
+                        regressions += 1;
+                        " REGRESSION"
+                    } else {
+                        ""
+                    };
+                    row.push(table::duration(previous));
+                    row.push(format!("{change:+.1}%{flag}"));
+                }
+                None if baseline.is_some() => row.extend(["-".into(), "-".into()]),
+                None => {}
+            }
+            table.push(row);
+        }
+    }
+    table.print();
+
+    if let Some(path) = save {
+        fs::write(&path, baseline_toml(&results))
+            .map_err(|e| format!("Can't write {}: {e}", path.display()))?;
+        println!();
+        println!("Saved medians to {}", path.display());
+    }
+    if baseline.is_some() {
+        println!();
+        println!("{regressions} regressions above {threshold}%");
+    }
+    Ok(regressions == 0)
+}
+
+fn measure(day: &Day, input: &str, runs: u32) -> Result<Timings> {
+    let mut parse = Vec::new();
+    let mut parts = Part::ALL.map(|_| Vec::new());
+    for _ in 0..runs {
+        let report = (day.solve)(input, &Part::ALL)?;
+        parse.push(report.parse_elapsed);
+        for (samples, answer) in parts.iter_mut().zip(report.answers) {
+            samples.push(answer.elapsed);
+        }
+    }
+    let mut stages = vec![("parse", Stats::from_samples(&parse))];
+    for (part, samples) in Part::ALL.into_iter().zip(&parts) {
+        stages.push((part_key(part), Stats::from_samples(samples)));
+    }
+    Ok(Timings {
+        label: format!("{}/{:02}", day.year, day.day),
+        stages,
+    })
+}
+
+fn baseline_toml(results: &[Timings]) -> String {
+    let mut text = String::from("# Median timings written by `aoc bench --save`.\n");
+    for timings in results {
+        text.push_str(&format!("\n[{}]\n", toml::quote(&timings.label)));
+        for (stage, stats) in &timings.stages {
+            text.push_str(&format!(
+                "{} = {}\n",
+                stage_key(stage),
+                stats.median.as_nanos()
+            ));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::bench::Stats;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.max, Duration::from_millis(4));
+    }
+}
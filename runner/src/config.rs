@@ -0,0 +1,70 @@
+use std::{
+    env, fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{toml, Result};
+
+/// Settings for talking to the puzzle site, read from `.aoc.toml` in the
+/// repository root (or `$AOC_CONFIG`), with `AOC_SESSION` and
+/// `AOC_BASE_URL` taking precedence over the file.
+pub struct Config {
+    pub path: PathBuf,
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Config {
+    pub const DEFAULT_BASE_URL: &'static str = "https://adventofcode.com";
+
+    pub fn path(root: &Path) -> PathBuf {
+        match env::var_os("AOC_CONFIG") {
+            Some(path) => path.into(),
+            None => root.join(".aoc.toml"),
+        }
+    }
+
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = Self::path(root);
+        let mut table = match fs::read_to_string(&path) {
+            Ok(text) => toml::parse(&text)
+                .map_err(|e| format!("{}: {}", path.display(), e.render(&text, false)))?
+                .remove("")
+                .unwrap_or_default(),
+            Err(e) if e.kind() == ErrorKind::NotFound => toml::Table::new(),
+            Err(e) => return Err(format!("Can't read {}: {e}", path.display()).into()),
+        };
+        let session = env::var("AOC_SESSION")
+            .ok()
+            .or(table.remove("session"))
+            .filter(|session| !session.is_empty());
+        let base_url = env::var("AOC_BASE_URL")
+            .ok()
+            .or(table.remove("base_url"))
+            .unwrap_or(Self::DEFAULT_BASE_URL.into());
+        Ok(Self {
+            path,
+            session,
+            base_url: base_url.trim_end_matches('/').into(),
+        })
+    }
+
+    pub fn session(&self) -> Result<&str> {
+        self.session.as_deref().ok_or_else(|| {
+            format!(
+                "No session token, set AOC_SESSION or `session` in {}",
+                self.path.display()
+            )
+            .into()
+        })
+    }
+
+    /// Request headers identifying the user to the site.
+    pub fn headers(&self) -> Result<Vec<(&'static str, String)>> {
+        Ok(vec![
+            ("Cookie", format!("session={}", self.session()?)),
+            ("User-Agent", "github.com/waciejm/aoc runner".into()),
+        ])
+    }
+}
@@ -0,0 +1,243 @@
+use std::{
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use aoc_common::{input, json, Part, Result};
+
+use crate::{
+    days::{crate_manifest, package_name, repo_root},
+    table::Table,
+};
+
+const USAGE: &str = "Usage: aoc crosscheck YEAR DAY";
+
+/// Answers one implementation printed, indexed like `Part::ALL`.
+pub type Answers = [Option<String>; 2];
+
+fn index(part: Part) -> usize {
+    match part {
+        Part::One => 0,
+        Part::Two => 1,
+    }
+}
+
+/// One solution of a day in some language, run as a subprocess.
+pub struct Implementation {
+    pub language: &'static str,
+    /// Program that has to be installed to run it.
+    pub toolchain: &'static str,
+    command: Command,
+    parse: fn(&str) -> Answers,
+}
+
+impl Implementation {
+    fn toolchain_installed(&self) -> bool {
+        Command::new(self.toolchain)
+            .arg("--version")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|status| status.success())
+    }
+
+    fn run(mut self) -> Result<Answers> {
+        let output = self
+            .command
+            .output()
+            .map_err(|e| format!("Can't run {}: {e}", self.toolchain))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let last = stderr.lines().rev().find(|l| !l.trim().is_empty());
+            return Err(format!("{}: {}", output.status, last.unwrap_or("")).into());
+        }
+        Ok((self.parse)(&String::from_utf8_lossy(&output.stdout)))
+    }
+}
+
+/// Parses the JSON records a Rust day binary prints with `--format json`.
+pub fn parse_json(output: &str) -> Answers {
+    let mut answers = Answers::default();
+    for fields in output.lines().filter_map(json::parse_flat) {
+        let part = fields.get("part").map(|part| part.parse::<Part>());
+        if let (Some(Ok(part)), Some(answer)) = (part, fields.get("answer")) {
+            answers[index(part)] = Some(answer.clone());
+        }
+    }
+    answers
+}
+
+/// Parses `part one: N` lines, as printed by the Gleam solutions.
+pub fn parse_lines(output: &str) -> Answers {
+    let mut answers = Answers::default();
+    for line in output.lines() {
+        let Some((label, value)) = line.split_once(':') else {
+            continue;
+        };
+        let Some(part) = label.trim().strip_prefix("part ") else {
+            continue;
+        };
+        if let Ok(part) = part.parse::<Part>() {
+            answers[index(part)].get_or_insert_with(|| value.trim().to_string());
+        }
+    }
+    answers
+}
+
+/// Parses the `{"part one": N, "part two": M}` record a Nushell solution's
+/// `main` returns, converted with `to json --raw`. Anything the script
+/// printed before it is skipped.
+pub fn parse_record(output: &str) -> Answers {
+    let mut answers = Answers::default();
+    let record = output.lines().rev().find_map(json::parse_flat);
+    for (key, value) in record.into_iter().flatten() {
+        let part = key.strip_prefix("part ").map(str::parse::<Part>);
+        if let Some(Ok(part)) = part {
+            answers[index(part)] = Some(value);
+        }
+    }
+    answers
+}
+
+/// Every implementation found in the day's directory, in a fixed order.
+pub fn implementations(root: &Path, year: u16, day: u8) -> Result<Vec<Implementation>> {
+    let dir = input::day_dir(root, year, day);
+    let input = input::default_path(root, year, day);
+    let mut found = Vec::new();
+
+    if let Some(manifest) = crate_manifest(root, year, day) {
+        let mut command = Command::new("cargo");
+        command
+            .args(["run", "--quiet", "--release", "--package"])
+            .arg(package_name(&manifest)?)
+            .args(["--", "--format", "json"])
+            .arg(&input)
+            .current_dir(root);
+        found.push(Implementation {
+            language: "rust",
+            toolchain: "cargo",
+            command,
+            parse: parse_json,
+        });
+    }
+
+    let gleam = dir.join("solution_gleam");
+    if gleam.join("gleam.toml").exists() {
+        // Gleam solutions read `../input` themselves.
+        let mut command = Command::new("gleam");
+        command.arg("run").current_dir(&gleam);
+        found.push(Implementation {
+            language: "gleam",
+            toolchain: "gleam",
+            command,
+            parse: parse_lines,
+        });
+    }
+
+    let script = dir.join("solution.nu");
+    if script.exists() {
+        let mut command = Command::new("nu");
+        command.arg("--commands").arg(format!(
+            "source {}; main {} | to json --raw",
+            nu_quote(&script),
+            nu_quote(&input)
+        ));
+        found.push(Implementation {
+            language: "nushell",
+            toolchain: "nu",
+            command,
+            parse: parse_record,
+        });
+    }
+    Ok(found)
+}
+
+fn nu_quote(path: &Path) -> String {
+    format!("'{}'", path.display())
+}
+
+/// Runs every implementation of a day on its input and reports whether
+/// the answers agree. Implementations without an installed toolchain are
+/// skipped, but a part fails unless at least two of them answered it.
+pub fn crosscheck(args: &[String]) -> Result<bool> {
+    let (year, day) = match args {
+        [year, day] => (year.parse::<u16>()?, day.parse::<u8>()?),
+        _ => return Err(USAGE.into()),
+    };
+    let root = repo_root();
+    let implementations = implementations(&root, year, day)?;
+    if implementations.is_empty() {
+        return Err(format!("No solutions found for {year} day {day}").into());
+    }
+
+    let mut table = Table::new(&["language", "part 1", "part 2", "status"]);
+    let mut results = Vec::new();
+    let mut failed = false;
+    for implementation in implementations {
+        let language = implementation.language;
+        if !implementation.toolchain_installed() {
+            let skipped = format!("skipped, {} not installed", implementation.toolchain);
+            table.push(vec![language.into(), "-".into(), "-".into(), skipped]);
+            continue;
+        }
+        match implementation.run() {
+            Ok(answers) => {
+                let mut row = vec![language.to_string()];
+                row.extend(answers.iter().map(|a| a.clone().unwrap_or("-".into())));
+                row.push("ok".into());
+                table.push(row);
+                results.push((language, answers));
+            }
+            Err(e) => {
+                failed = true;
+                table.push(vec![language.into(), "-".into(), "-".into(), e.to_string()]);
+            }
+        }
+    }
+    table.print();
+    println!();
+
+    let mut agree = !failed;
+    for part in Part::ALL {
+        let mut given = results
+            .iter()
+            .filter_map(|(language, answers)| Some((*language, answers[index(part)].as_ref()?)));
+        let Some((first_language, first)) = given.next() else {
+            agree = false;
+            println!("part {part}: no answers");
+            continue;
+        };
+        let others = given.collect::<Vec<_>>();
+        match others.iter().find(|(_, answer)| *answer != first) {
+            Some((language, answer)) => {
+                agree = false;
+                println!("part {part}: DISAGREE, {first_language} says {first}, {language} says {answer}");
+            }
+            None if others.is_empty() => {
+                agree = false;
+                println!(
+                    "part {part}: only {first_language} answered, {first}, nothing to compare"
+                );
+            }
+            None => println!("part {part}: all agree on {first}"),
+        }
+    }
+    Ok(agree)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::crosscheck::{parse_json, parse_lines, parse_record};
+
+    #[test]
+    fn test_parse_outputs() {
+        let expected = [Some("135169".to_string()), Some("302133440".to_string())];
+        let gleam = "  Compiling solution_gleam\npart one: 135169\npart two: 302133440\n";
+        assert_eq!(parse_lines(gleam), expected);
+        let nu = "\n--- part one ---\n{\"part one\":135169,\"part two\":302133440}\n";
+        assert_eq!(parse_record(nu), expected);
+        assert_eq!(parse_lines("Part 1\n72602\n"), [None, None]);
+        let rust = "{\"year\":2022,\"day\":1,\"part\":1,\"answer\":\"72602\",\"parse_ns\":1}\n";
+        assert_eq!(parse_json(rust), [Some("72602".to_string()), None]);
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub use aoc_common::input::repo_root;
+
+use aoc_common::{
+    input::{self, Source},
+    solve, solve_with, toml, Part, Report, Result, Solution,
+};
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solve: fn(&str, &[Part]) -> Result<Report>,
+    /// Like `solve`, with parameters such as an example fixture sets.
+    pub solve_with: fn(&str, &[Part], &toml::Table) -> Result<Report>,
+}
+
+fn solve_configured<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    parameters: &toml::Table,
+) -> Result<Report> {
+    solve_with::<S>(input, parts, |parsed| {
+        parameters
+            .iter()
+            .try_for_each(|(name, value)| S::configure(parsed, name, value))
+    })
+}
+
+fn day<S: Solution>() -> Day {
+    Day {
+        year: S::YEAR,
+        day: S::DAY,
+        solve: solve::<S>,
+        solve_with: solve_configured::<S>,
+    }
+}
+
+pub fn days() -> Vec<Day> {
+    vec![
+        day::<aoc2022_01::Day01>(),
+        day::<aoc2022_02::Day02>(),
+        day::<aoc2022_03::Day03>(),
+        day::<aoc2022_04::Day04>(),
+        day::<aoc2022_05::Day05>(),
+        day::<aoc2022_06::Day06>(),
+        day::<aoc2022_07::Day07>(),
+        day::<aoc2022_08::Day08>(),
+        day::<aoc2025_08::Day08>(),
+    ]
+}
+
+/// Days matching the optional `YEAR [DAY]` arguments, failing if none do.
+pub fn select(args: &[String]) -> Result<Vec<Day>> {
+    let (year, day) = match args {
+        [] => (None, None),
+        [year] => (Some(year.parse::<u16>()?), None),
+        [year, day] => (Some(year.parse()?), Some(day.parse::<u8>()?)),
+        _ => return Err("Expected at most YEAR and DAY".into()),
+    };
+    let selected = days()
+        .into_iter()
+        .filter(|d| year.is_none_or(|y| d.year == y) && day.is_none_or(|n| d.day == n))
+        .collect::<Vec<_>>();
+    if selected.is_empty() {
+        return Err("No solutions match".into());
+    }
+    Ok(selected)
+}
+
+impl Day {
+    pub fn find(year: u16, day: u8) -> Option<Day> {
+        days().into_iter().find(|d| d.year == year && d.day == day)
+    }
+
+    pub fn dir(&self, root: &Path) -> PathBuf {
+        input::day_dir(root, self.year, self.day)
+    }
+
+    pub fn input_path(&self, root: &Path) -> PathBuf {
+        input::default_path(root, self.year, self.day)
+    }
+
+    /// The committed input, normalized like day binaries do.
+    pub fn load_input(&self, root: &Path) -> Result<String> {
+        Source::Path(self.input_path(root)).load()
+    }
+}
+
+/// The Rust crate of a day, which needn't be registered yet: `YEAR/DD`
+/// up to 2022, `YEAR/DD/solution_rust` after.
+pub fn crate_manifest(root: &Path, year: u16, day: u8) -> Option<PathBuf> {
+    let dir = input::day_dir(root, year, day);
+    [dir.join("Cargo.toml"), dir.join("solution_rust/Cargo.toml")]
+        .into_iter()
+        .find(|manifest| manifest.exists())
+}
+
+pub fn package_name(manifest: &Path) -> Result<String> {
+    let text = fs::read_to_string(manifest)
+        .map_err(|e| format!("Can't read {}: {e}", manifest.display()))?;
+    let tables = toml::parse(&text)?;
+    let name = tables
+        .get("package")
+        .and_then(|package| package.get("name"));
+    Ok(name
+        .ok_or(format!("{} has no package name", manifest.display()))?
+        .clone())
+}
@@ -0,0 +1,105 @@
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{input::Source, toml, Part, Result};
+
+use crate::{answers::part_key, days::Day};
+
+/// A worked example from a puzzle statement: `DAY/examples/NAME.input` with
+/// the expected answers in `NAME.toml`, using the keys of `answers.toml`.
+/// Any other key there is a parameter the example needs, see
+/// `Solution::configure`.
+pub struct Example {
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: PathBuf,
+}
+
+impl Example {
+    fn table(&self) -> Result<toml::Table> {
+        let expected = fs::read_to_string(&self.expected)
+            .map_err(|e| format!("Can't read {}: {e}", self.expected.display()))?;
+        Ok(toml::parse(&expected)?.remove("").unwrap_or_default())
+    }
+
+    /// Expected answers keyed like `answers.toml`.
+    pub fn expected(&self) -> Result<toml::Table> {
+        let mut table = self.table()?;
+        table.retain(|key, _| is_part_key(key));
+        Ok(table)
+    }
+
+    /// Parameters to solve the example with, by name.
+    pub fn parameters(&self) -> Result<toml::Table> {
+        let mut table = self.table()?;
+        table.retain(|key, _| !is_part_key(key));
+        Ok(table)
+    }
+}
+
+fn is_part_key(key: &str) -> bool {
+    Part::ALL.into_iter().any(|part| part_key(part) == key)
+}
+
+pub fn find(root: &Path, day: &Day) -> Result<Vec<Example>> {
+    find_in(&day.dir(root))
+}
+
+/// Examples in `DAY_DIR/examples`, sorted by name.
+pub fn find_in(day_dir: &Path) -> Result<Vec<Example>> {
+    let dir = day_dir.join("examples");
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Can't read {}: {e}", dir.display()).into()),
+    };
+    let mut examples = Vec::new();
+    for entry in entries {
+        let input = entry?.path();
+        if input.extension().is_none_or(|ext| ext != "input") {
+            continue;
+        }
+        let name = input
+            .file_stem()
+            .ok_or("Example without a name")?
+            .to_string_lossy()
+            .into_owned();
+        examples.push(Example {
+            name,
+            expected: input.with_extension("toml"),
+            input,
+        });
+    }
+    examples.sort_by(|l, r| l.name.cmp(&r.name));
+    Ok(examples)
+}
+
+/// Runs `example` through `day`, describing every part that didn't match.
+/// Parts without an expected answer are skipped.
+pub fn check(day: &Day, example: &Example) -> Result<Vec<String>> {
+    let input = Source::Path(example.input.clone()).load()?;
+    let expected = example.expected()?;
+    let parameters = example.parameters()?;
+
+    let parts = Part::ALL
+        .into_iter()
+        .filter(|part| expected.contains_key(part_key(*part)))
+        .collect::<Vec<_>>();
+    let report = (day.solve_with)(&input, &parts, &parameters)?;
+    Ok(report
+        .answers
+        .into_iter()
+        .filter_map(|answer| {
+            let expected = &expected[part_key(answer.part)];
+            (*expected != answer.value).then(|| {
+                format!(
+                    "part {}: expected {expected}, got {}",
+                    answer.part, answer.value
+                )
+            })
+        })
+        .collect())
+}
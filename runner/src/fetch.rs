@@ -0,0 +1,73 @@
+use std::{fs, path::Path};
+
+use aoc_common::{input, Result};
+
+use crate::{config::Config, days::repo_root, http};
+
+const USAGE: &str = "Usage: aoc fetch YEAR DAY [--base-url URL]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the input of `year` `day` into `path` unless it already exists.
+/// An empty file, like the placeholder from `aoc new`, doesn't count.
+pub fn download(config: &Config, year: u16, day: u8, path: &Path) -> Result<Fetched> {
+    if fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+    let url = format!("{}/{year}/day/{day}/input", config.base_url);
+    let headers = config.headers()?;
+    let headers = headers
+        .iter()
+        .map(|(name, value)| (*name, value.as_str()))
+        .collect::<Vec<_>>();
+    let response = http::get(&url, &headers)?;
+    if !response.is_success() {
+        return Err(format!(
+            "GET {url} returned {}: {}",
+            response.status,
+            response.body.trim()
+        )
+        .into());
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    // Written next to the target and renamed so an interrupted download never
+    // looks like a cached input.
+    let partial = path.with_extension("partial");
+    fs::write(&partial, &response.body)?;
+    fs::rename(&partial, path)?;
+    Ok(Fetched::Downloaded)
+}
+
+pub fn fetch(args: &[String]) -> Result<bool> {
+    let mut positional = Vec::new();
+    let mut base_url = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--base-url" => base_url = Some(args.next().ok_or("Missing value for --base-url")?),
+            _ => positional.push(arg),
+        }
+    }
+    let (year, day) = match positional[..] {
+        [year, day] => (year.parse::<u16>()?, day.parse::<u8>()?),
+        _ => return Err(USAGE.into()),
+    };
+
+    let root = repo_root();
+    let mut config = Config::load(&root)?;
+    if let Some(base_url) = base_url {
+        config.base_url = base_url.trim_end_matches('/').into();
+    }
+    let path = input::default_path(&root, year, day);
+    match download(&config, year, day, &path)? {
+        Fetched::Cached => println!("{} already present", path.display()),
+        Fetched::Downloaded => println!("Saved {}", path.display()),
+    }
+    Ok(true)
+}
@@ -0,0 +1,161 @@
+//! Just enough HTTP for talking to the puzzle site. Plain `http://` URLs are
+//! spoken to directly over a `TcpStream`, which is what local mock servers
+//! use; `https://` goes through the system `curl` since std has no TLS.
+
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+};
+
+use aoc_common::Result;
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+pub fn get(url: &str, headers: &[(&str, &str)]) -> Result<Response> {
+    request("GET", url, headers, None)
+}
+
+/// POSTs `body` as `application/x-www-form-urlencoded`.
+pub fn post_form(url: &str, headers: &[(&str, &str)], body: &str) -> Result<Response> {
+    let mut headers = headers.to_vec();
+    headers.push(("Content-Type", "application/x-www-form-urlencoded"));
+    request("POST", url, &headers, Some(body))
+}
+
+fn request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response> {
+    let raw = if let Some(rest) = url.strip_prefix("http://") {
+        plain(method, rest, headers, body)?
+    } else if url.starts_with("https://") {
+        curl(method, url, headers, body)?
+    } else {
+        return Err(format!("Unsupported URL {url}").into());
+    };
+    parse_response(&raw).ok_or_else(|| format!("Malformed HTTP response from {url}").into())
+}
+
+/// HTTP/1.0 so the server closes the connection and never chunks the body.
+fn plain(method: &str, url: &str, headers: &[(&str, &str)], body: Option<&str>) -> Result<String> {
+    let (host, path) = match url.find('/') {
+        Some(slash) => url.split_at(slash),
+        None => (url, "/"),
+    };
+    let address = match host.contains(':') {
+        true => host.to_string(),
+        false => format!("{host}:80"),
+    };
+    let mut stream =
+        TcpStream::connect(&address).map_err(|e| format!("Can't connect to {address}: {e}"))?;
+    let mut request = format!("{method} {path} HTTP/1.0\r\nHost: {host}\r\n");
+    for (name, value) in headers {
+        request.push_str(&format!("{name}: {value}\r\n"));
+    }
+    if let Some(body) = body {
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("\r\n");
+    request.push_str(body.unwrap_or(""));
+    stream.write_all(request.as_bytes())?;
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw)?;
+    Ok(String::from_utf8(raw)?)
+}
+
+/// Options for `curl --config -`. Headers are passed this way rather than
+/// as arguments, which every local user can read while curl runs.
+fn curl_config(method: &str, headers: &[(&str, &str)], body: Option<&str>) -> String {
+    let quote = |value: &str| {
+        let mut quoted = String::from('"');
+        for c in value.chars() {
+            match c {
+                '"' => quoted.push_str("\\\""),
+                '\\' => quoted.push_str("\\\\"),
+                '\n' => quoted.push_str("\\n"),
+                '\r' => quoted.push_str("\\r"),
+                '\t' => quoted.push_str("\\t"),
+                c => quoted.push(c),
+            }
+        }
+        quoted.push('"');
+        quoted
+    };
+    let mut config = format!("request = {}\n", quote(method));
+    for (name, value) in headers {
+        config.push_str(&format!(
+            "header = {}\n",
+            quote(&format!("{name}: {value}"))
+        ));
+    }
+    if let Some(body) = body {
+        // Unlike `data-binary`, never reads a file for a leading `@`.
+        config.push_str(&format!("data-raw = {}\n", quote(body)));
+    }
+    config
+}
+
+fn curl(method: &str, url: &str, headers: &[(&str, &str)], body: Option<&str>) -> Result<String> {
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--include", "--config", "-"])
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Can't run curl for https: {e}"))?;
+    let config = curl_config(method, headers, body);
+    child.stdin.take().unwrap().write_all(config.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(format!(
+            "curl failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )
+        .into());
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+fn parse_response(raw: &str) -> Option<Response> {
+    let (head, body) = raw.split_once("\r\n\r\n")?;
+    let status = head
+        .lines()
+        .next()?
+        .split_whitespace()
+        .nth(1)?
+        .parse()
+        .ok()?;
+    Some(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::http::curl_config;
+
+    #[test]
+    fn test_curl_config() {
+        let headers = [("Cookie", "session=abc\"def")];
+        let config = curl_config("POST", &headers, Some("level=1&answer=@x\\y"));
+        assert_eq!(
+            config,
+            "request = \"POST\"\nheader = \"Cookie: session=abc\\\"def\"\n\
+             data-raw = \"level=1&answer=@x\\\\y\"\n"
+        );
+    }
+}
@@ -0,0 +1,15 @@
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod crosscheck;
+pub mod days;
+pub mod examples;
+pub mod fetch;
+pub mod http;
+pub mod pool;
+pub mod run;
+pub mod scaffold;
+pub mod submit;
+pub mod table;
+pub mod verify;
+pub mod watch;
@@ -0,0 +1,45 @@
+use std::process::ExitCode;
+
+use aoc::{bench, crosscheck, fetch, run, scaffold, submit, verify, watch};
+use aoc_common::Result;
+
+const USAGE: &str = "\
+Usage: aoc run YEAR DAY [--part N] [--color]
+       aoc run --all [--year YEAR] [--part N]
+       aoc verify [YEAR [DAY]]
+       aoc bench [YEAR [DAY]] [--runs N] [--baseline FILE] [--save FILE] [--threshold PCT]
+       aoc fetch YEAR DAY [--base-url URL]
+       aoc submit YEAR DAY PART [ANSWER] [--base-url URL]
+       aoc new YEAR DAY [--gleam] [--nu]
+       aoc crosscheck YEAR DAY
+       aoc watch YEAR DAY [--interval MS]";
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match dispatch(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn dispatch(args: &[String]) -> Result<bool> {
+    match args.first().map(String::as_str) {
+        Some("run") => run::run(&args[1..]),
+        Some("verify") => verify::verify(&args[1..]),
+        Some("bench") => bench::bench(&args[1..]),
+        Some("fetch") => fetch::fetch(&args[1..]),
+        Some("submit") => submit::submit(&args[1..]),
+        Some("new") => scaffold::new(&args[1..]),
+        Some("crosscheck") => crosscheck::crosscheck(&args[1..]),
+        Some("watch") => watch::watch(&args[1..]),
+        Some("help" | "--help" | "-h") => {
+            println!("{USAGE}");
+            Ok(true)
+        }
+        _ => Err(USAGE.into()),
+    }
+}
@@ -0,0 +1,73 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+/// Applies `f` to every item on up to `threads` scoped worker threads,
+/// returning results in the order of `items`. A panicking call yields an
+/// `Err` with the panic message instead of bringing the other workers down.
+pub fn map<T, R, F>(items: &[T], threads: usize, f: F) -> Vec<Result<R, String>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let result =
+                    panic::catch_unwind(AssertUnwindSafe(|| f(item))).map_err(panic_message);
+                results.lock().unwrap()[index] = Some(result);
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every item is taken by a worker"))
+        .collect()
+}
+
+/// Number of workers to use when the caller has no preference.
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "panicked".into(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::pool::map;
+
+    #[test]
+    fn test_map_keeps_order_and_survives_panics() {
+        let items = (0..20).collect::<Vec<u32>>();
+        let results = map(&items, 4, |&n| {
+            assert!(n != 7, "seven");
+            n * 2
+        });
+        assert_eq!(results.len(), 20);
+        assert_eq!(results[3], Ok(6));
+        assert_eq!(results[7], Err("seven".into()));
+        assert_eq!(results[19], Ok(38));
+    }
+}
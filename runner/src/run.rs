@@ -0,0 +1,131 @@
+use aoc_common::{ParseError, Part, Result};
+
+use crate::{
+    days::{days, repo_root, Day},
+    pool,
+    table::{self, Table},
+};
+
+const USAGE: &str = "\
+Usage: aoc run YEAR DAY [--part N] [--color]
+       aoc run --all [--year YEAR] [--part N]";
+
+/// Solves one day on its committed input, printing answers and timings.
+pub fn run(args: &[String]) -> Result<bool> {
+    let mut positional = Vec::new();
+    let mut part = None;
+    let mut color = false;
+    let mut all = false;
+    let mut only_year = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = args.next().ok_or("Missing value for --part")?;
+                part = Some(value.parse::<Part>()?);
+            }
+            "--color" => color = true,
+            "--all" => all = true,
+            "--year" => {
+                let value = args.next().ok_or("Missing value for --year")?;
+                only_year = Some(value.parse::<u16>()?);
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    if all {
+        if !positional.is_empty() {
+            return Err(USAGE.into());
+        }
+        return run_all(only_year, &parts);
+    }
+    if only_year.is_some() {
+        return Err(USAGE.into());
+    }
+    let (year, day) = match positional[..] {
+        [year, day] => (year.parse()?, day.parse()?),
+        _ => return Err(USAGE.into()),
+    };
+
+    let day = Day::find(year, day).ok_or(format!("No solution for {year} day {day}"))?;
+    let input = day.load_input(&repo_root())?;
+
+    let report = match (day.solve)(&input, &parts) {
+        Ok(report) => report,
+        Err(e) => match e.downcast_ref::<ParseError>() {
+            Some(e) => {
+                eprint!("{}", e.render(&input, color));
+                return Ok(false);
+            }
+            None => return Err(e),
+        },
+    };
+    println!("{} day {:02}", day.year, day.day);
+    println!("parse: {:?}", report.parse_elapsed);
+    for answer in report.answers {
+        println!(
+            "part {}: {} ({:?})",
+            answer.part, answer.value, answer.elapsed
+        );
+    }
+    Ok(true)
+}
+
+/// Solves every registered day of `year` (or of all years) concurrently and
+/// prints one table row per day. A failing day is reported in its row while
+/// the others keep running.
+fn run_all(year: Option<u16>, parts: &[Part]) -> Result<bool> {
+    let selected = days()
+        .into_iter()
+        .filter(|d| year.is_none_or(|y| d.year == y))
+        .collect::<Vec<_>>();
+    if selected.is_empty() {
+        return Err("No solutions match".into());
+    }
+    let root = repo_root();
+    let results = pool::map(&selected, pool::default_threads(), |day| {
+        day.load_input(&root)
+            .and_then(|input| (day.solve)(&input, parts))
+            .map_err(|e| e.to_string())
+    });
+
+    let mut header = vec!["day".to_string(), "parse".to_string()];
+    for part in parts {
+        header.extend([format!("part {part}"), "time".to_string()]);
+    }
+    let mut table = Table::new(&header.iter().map(String::as_str).collect::<Vec<_>>());
+    let mut failures = Vec::new();
+    for (day, result) in selected.iter().zip(results) {
+        let label = format!("{}/{:02}", day.year, day.day);
+        let mut row = vec![label.clone()];
+        match result.and_then(|report| report) {
+            Ok(report) => {
+                row.push(table::duration(report.parse_elapsed));
+                for answer in report.answers {
+                    row.extend([answer.value, table::duration(answer.elapsed)]);
+                }
+            }
+            Err(e) => {
+                row.push("FAILED".into());
+                failures.push((label, e));
+            }
+        }
+        table.push(row);
+    }
+    table.print();
+
+    println!();
+    println!(
+        "{} solved, {} failed",
+        selected.len() - failures.len(),
+        failures.len()
+    );
+    for (label, error) in &failures {
+        println!("{label}: {error}");
+    }
+    Ok(failures.is_empty())
+}
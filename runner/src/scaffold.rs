@@ -0,0 +1,391 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{input, Result};
+
+use crate::days::{days, repo_root};
+
+const USAGE: &str = "Usage: aoc new YEAR DAY [--gleam] [--nu]";
+
+/// Where the pieces of a new day go. Up to 2022 a day is a single crate in
+/// `YEAR/DD`, from 2025 the Rust crate is one of several `solution_*`
+/// siblings sharing `YEAR/DD/input`.
+pub struct Layout {
+    pub year: u16,
+    pub day: u8,
+    pub dir: PathBuf,
+    pub crate_dir: PathBuf,
+}
+
+impl Layout {
+    pub fn new(root: &Path, year: u16, day: u8) -> Self {
+        let dir = input::day_dir(root, year, day);
+        let crate_dir = match year {
+            ..=2022 => dir.clone(),
+            _ => dir.join("solution_rust"),
+        };
+        Self {
+            year,
+            day,
+            dir,
+            crate_dir,
+        }
+    }
+
+    pub fn package(&self) -> String {
+        format!("aoc{}-{:02}", self.year, self.day)
+    }
+
+    fn crate_name(&self) -> String {
+        self.package().replace('-', "_")
+    }
+
+    fn solution(&self) -> String {
+        format!("Day{:02}", self.day)
+    }
+
+    /// The crate's path relative to the repository root, with `/`.
+    fn member(&self) -> String {
+        match self.year {
+            ..=2022 => format!("{}/{:02}", self.year, self.day),
+            _ => format!("{}/{:02}/solution_rust", self.year, self.day),
+        }
+    }
+
+    /// 2022 days kept `cargo new`'s manifest, 2025 ones moved to edition 2024.
+    fn cargo_toml(&self) -> String {
+        let (edition, comment) = match self.year {
+            ..=2022 => ("2021", MANIFEST_COMMENT),
+            _ => ("2024", ""),
+        };
+        format!(
+            "\
+[package]
+name = \"{}\"
+version = \"0.1.0\"
+edition = \"{edition}\"
+{comment}
+[dependencies]
+aoc-common = {{ workspace = true }}
+",
+            self.package()
+        )
+    }
+
+    fn lib_rs(&self) -> String {
+        format!(
+            "\
+use aoc_common::{{{imports}}};
+
+pub struct {solution};
+
+impl Solution for {solution} {{
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    type Parsed = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {{
+        Ok(lines(input).map(|line| line.text.to_string()).collect())
+    }}
+
+    fn part_one(_input: &Self::Parsed) -> Result<Self::PartOne> {{
+        Err(\"Part one not solved yet\".into())
+    }}
+
+    fn part_two(_input: &Self::Parsed) -> Result<Self::PartTwo> {{
+        Err(\"Part two not solved yet\".into())
+    }}
+}}
+",
+            imports = match self.year {
+                ..=2022 => "parse::lines, ParseError, Result, Solution",
+                _ => "ParseError, Result, Solution, parse::lines",
+            },
+            solution = self.solution(),
+            year = self.year,
+            day = self.day,
+        )
+    }
+
+    fn main_rs(&self) -> String {
+        // rustfmt orders imports differently between the two editions.
+        let day = format!("use {}::{};", self.crate_name(), self.solution());
+        let imports = match self.year {
+            ..=2022 => format!("{day}\nuse aoc_common::{{cli, Part}};"),
+            _ => format!("use aoc_common::{{Part, cli}};\n{day}"),
+        };
+        let print = match self.year {
+            ..=2022 => PRINT_2022,
+            _ => PRINT_2025,
+        };
+        format!(
+            "\
+use std::process::ExitCode;
+
+{imports}
+
+fn main() -> ExitCode {{
+    cli::run::<{solution}>(|answer| match answer.part {{
+{print}    }})
+}}
+",
+            solution = self.solution(),
+        )
+    }
+}
+
+// Kept as plain literals, a `\` line continuation would eat the indentation.
+const PRINT_2022: &str = r#"        Part::One => {
+            println!("Part 1");
+            println!("{}", answer.value);
+            println!();
+        }
+        Part::Two => {
+            println!("Part 2");
+            println!("{}", answer.value);
+        }
+"#;
+
+const PRINT_2025: &str = r#"        Part::One => println!("part one: {}", answer.value),
+        Part::Two => println!("part two: {}", answer.value),
+"#;
+
+const MANIFEST_COMMENT: &str = "
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+";
+
+const EXAMPLE_TOML: &str = "\
+# Expected answers for example.input, checked by `cargo test -p aoc`.
+# part_one = \"\"
+# part_two = \"\"
+# Other keys are parameters passed to `Solution::configure`.
+";
+
+const GLEAM_TOML: &str = "\
+name = \"solution_gleam\"
+version = \"1.0.0\"
+
+[dependencies]
+gleam_stdlib = \">= 0.44.0 and < 2.0.0\"
+simplifile = \">= 2.3.1 and < 3.0.0\"
+
+[dev-dependencies]
+gleeunit = \">= 1.0.0 and < 2.0.0\"
+";
+
+const GLEAM_GITIGNORE: &str = "*.beam\n*.ez\n/build\nerl_crash.dump\n";
+
+const NU: &str = "\
+#!/usr/bin/env nu
+
+def main [input: path] {
+  let rows = open $input | lines
+  {
+    \"part one\": 0
+    \"part two\": 0
+  }
+}
+";
+
+fn gleam_main(input: &str) -> String {
+    format!(
+        "\
+import gleam/int
+import gleam/io
+import gleam/string
+import simplifile
+
+pub fn main() -> Nil {{
+  let assert Ok(input) = simplifile.read(\"../{input}\")
+  let rows = input |> string.trim |> string.split(\"\\n\")
+  io.println(\"part one: \" <> int.to_string(part_one(rows)))
+  io.println(\"part two: \" <> int.to_string(part_two(rows)))
+}}
+
+fn part_one(_rows: List(String)) -> Int {{
+  0
+}}
+
+fn part_two(_rows: List(String)) -> Int {{
+  0
+}}
+"
+    )
+}
+
+/// Adds `line` to the sorted run of lines that `after` starts and a line
+/// equal to `end` (or the end of `text`) closes.
+pub fn insert_sorted(text: &str, after: &str, end: &str, line: &str) -> Result<String> {
+    let mut lines = text.lines().map(String::from).collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|l| l == after)
+        .ok_or(format!("Can't find `{after}`"))?
+        + 1;
+    let close = lines[start..]
+        .iter()
+        .position(|l| l == end)
+        .map_or(lines.len(), |i| start + i);
+    if lines[start..close].iter().any(|l| l == line) {
+        return Err(format!("`{}` is already there", line.trim()).into());
+    }
+    let at = start + lines[start..close].partition_point(|l| l.as_str() < line);
+    lines.insert(at, line.into());
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The new contents of `path` after `change`, without writing them yet.
+fn edit(path: PathBuf, change: impl FnOnce(&str) -> Result<String>) -> Result<(PathBuf, String)> {
+    let text =
+        fs::read_to_string(&path).map_err(|e| format!("Can't read {}: {e}", path.display()))?;
+    let changed = change(&text).map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok((path, changed))
+}
+
+/// Generates the Rust crate of a new day and registers it in the workspace
+/// and the runner, plus example fixtures and an empty input to fetch into.
+/// Nothing is written if any generated file already exists or registering
+/// the day fails.
+pub fn scaffold(root: &Path, layout: &Layout, gleam: bool, nu: bool) -> Result<()> {
+    let (year, day) = (layout.year, layout.day);
+    if days().iter().any(|d| d.year == year && d.day == day) {
+        return Err(format!("{year} day {day} is already registered").into());
+    }
+
+    let mut files = vec![
+        (layout.crate_dir.join("Cargo.toml"), layout.cargo_toml()),
+        (layout.crate_dir.join(".gitignore"), "/target\n".into()),
+        (layout.crate_dir.join("src/lib.rs"), layout.lib_rs()),
+        (layout.crate_dir.join("src/main.rs"), layout.main_rs()),
+        (layout.dir.join("examples/example.input"), String::new()),
+        (
+            layout.dir.join("examples/example.toml"),
+            EXAMPLE_TOML.into(),
+        ),
+    ];
+    let input = input::default_path(root, year, day);
+    if gleam {
+        let dir = layout.dir.join("solution_gleam");
+        let input = input.file_name().unwrap_or_default().to_string_lossy();
+        files.push((dir.join("gleam.toml"), GLEAM_TOML.into()));
+        files.push((dir.join(".gitignore"), GLEAM_GITIGNORE.into()));
+        files.push((dir.join("src/solution_gleam.gleam"), gleam_main(&input)));
+    }
+    if nu {
+        files.push((layout.dir.join("solution.nu"), NU.into()));
+    }
+    if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+        return Err(format!("{} already exists", path.display()).into());
+    }
+    // A fetched input is kept, otherwise an empty one marks where it goes.
+    if !input.exists() {
+        files.push((input, String::new()));
+    }
+
+    let registry = [
+        edit(root.join("Cargo.toml"), |text| {
+            insert_sorted(
+                text,
+                "members = [",
+                "]",
+                &format!("    \"{}\",", layout.member()),
+            )
+        })?,
+        edit(root.join("runner/Cargo.toml"), |text| {
+            let line = format!(
+                "{} = {{ path = \"../{}\" }}",
+                layout.package(),
+                layout.member()
+            );
+            insert_sorted(text, "[dependencies]", "", &line)
+        })?,
+        edit(root.join("runner/src/days.rs"), |text| {
+            let line = format!(
+                "        day::<{}::{}>(),",
+                layout.crate_name(),
+                layout.solution()
+            );
+            insert_sorted(text, "    vec![", "    ]", &line)
+        })?,
+    ];
+
+    for (path, contents) in &files {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, contents).map_err(|e| format!("Can't write {}: {e}", path.display()))?;
+        println!("created {}", path.display());
+    }
+    for (path, contents) in &registry {
+        fs::write(path, contents).map_err(|e| format!("Can't write {}: {e}", path.display()))?;
+    }
+    println!(
+        "registered {} in the workspace and runner",
+        layout.package()
+    );
+    Ok(())
+}
+
+pub fn new(args: &[String]) -> Result<bool> {
+    let mut positional = Vec::new();
+    let (mut gleam, mut nu) = (false, false);
+    for arg in args {
+        match arg.as_str() {
+            "--gleam" => gleam = true,
+            "--nu" => nu = true,
+            _ => positional.push(arg),
+        }
+    }
+    let (year, day) = match positional[..] {
+        [year, day] => (year.parse::<u16>()?, day.parse::<u8>()?),
+        _ => return Err(USAGE.into()),
+    };
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day must be between 1 and 25, got {day}").into());
+    }
+    let root = repo_root();
+    scaffold(&root, &Layout::new(&root, year, day), gleam, nu)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs};
+
+    use crate::scaffold::{insert_sorted, scaffold, Layout};
+
+    #[test]
+    fn test_insert_sorted() {
+        let manifest = "members = [\n    \"2022/01\",\n    \"2025/08\",\n]\n";
+        let changed = insert_sorted(manifest, "members = [", "]", "    \"2022/09\",").unwrap();
+        assert_eq!(
+            changed,
+            "members = [\n    \"2022/01\",\n    \"2022/09\",\n    \"2025/08\",\n]\n"
+        );
+        assert!(insert_sorted(&changed, "members = [", "]", "    \"2022/09\",").is_err());
+    }
+
+    #[test]
+    fn test_failed_registration_writes_nothing() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("runner/src")).unwrap();
+        fs::write(root.join("Cargo.toml"), "members = [\"2022/01\"]\n").unwrap();
+        fs::write(root.join("runner/Cargo.toml"), "[dependencies]\n").unwrap();
+        fs::write(root.join("runner/src/days.rs"), "    vec![\n    ]\n").unwrap();
+
+        let layout = Layout::new(&root, 2022, 9);
+        let err = scaffold(&root, &layout, false, false).unwrap_err();
+        assert!(err.to_string().contains("Can't find `members = [`"));
+        assert!(!layout.dir.exists());
+        assert_eq!(
+            fs::read_to_string(root.join("runner/Cargo.toml")).unwrap(),
+            "[dependencies]\n"
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
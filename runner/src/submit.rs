@@ -0,0 +1,326 @@
+use std::{
+    fmt, fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::{toml, Part, Result};
+
+use crate::{
+    config::Config,
+    days::{repo_root, Day},
+    http,
+};
+
+const USAGE: &str = "Usage: aoc submit YEAR DAY PART [ANSWER] [--base-url URL]";
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the previous attempt, nothing was checked.
+    Wait,
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    const ALL: [Outcome; 7] = [
+        Self::Correct,
+        Self::Wrong,
+        Self::TooHigh,
+        Self::TooLow,
+        Self::Wait,
+        Self::WrongLevel,
+        Self::Unknown,
+    ];
+
+    pub fn from_response(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            Self::Correct
+        } else if body.contains("your answer is too high") {
+            Self::TooHigh
+        } else if body.contains("your answer is too low") {
+            Self::TooLow
+        } else if body.contains("That's not the right answer") {
+            Self::Wrong
+        } else if body.contains("You gave an answer too recently") {
+            Self::Wait
+        } else if body.contains("solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Whether the answer was checked and rejected.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Self::Wrong | Self::TooHigh | Self::TooLow)
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::Wait => "wait",
+            Self::WrongLevel => "wrong level",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|outcome| outcome.label() == label)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Every answer submitted for a year, stored in `YEAR/submissions.toml` as
+/// one `["DD/PART"]` table mapping answers to outcomes, plus a root
+/// `wait_until` timestamp when the site asked us to back off.
+pub struct History {
+    path: PathBuf,
+    tables: toml::Tables,
+}
+
+impl History {
+    pub fn path(root: &Path, year: u16) -> PathBuf {
+        root.join(year.to_string()).join("submissions.toml")
+    }
+
+    pub fn load(path: PathBuf) -> Result<Self> {
+        let tables = match fs::read_to_string(&path) {
+            Ok(text) => toml::parse(&text)
+                .map_err(|e| format!("{}: {}", path.display(), e.render(&text, false)))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => toml::Tables::new(),
+            Err(e) => return Err(format!("Can't read {}: {e}", path.display()).into()),
+        };
+        Ok(Self { path, tables })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(&self.path, toml::to_string(&self.tables))
+            .map_err(|e| format!("Can't write {}: {e}", self.path.display()).into())
+    }
+
+    fn key(day: u8, part: Part) -> String {
+        format!("{day:02}/{part}")
+    }
+
+    pub fn attempts(&self, day: u8, part: Part) -> impl Iterator<Item = (&str, Outcome)> {
+        self.tables
+            .get(&Self::key(day, part))
+            .into_iter()
+            .flatten()
+            .map(|(answer, outcome)| {
+                let outcome = Outcome::from_label(outcome).unwrap_or(Outcome::Unknown);
+                (answer.as_str(), outcome)
+            })
+    }
+
+    /// Why `answer` must not be sent, if the history already rules it out.
+    pub fn refusal(&self, day: u8, part: Part, answer: &str, now: u64) -> Option<String> {
+        if let Some(wait_until) = self.wait_until().filter(|&t| t > now) {
+            return Some(format!("Rate limited for another {}s", wait_until - now));
+        }
+        let number = answer.parse::<i128>().ok();
+        for (previous, outcome) in self.attempts(day, part) {
+            let refused = match outcome {
+                Outcome::Correct => return Some(format!("Already solved with {previous}")),
+                _ if previous == answer && outcome.is_wrong() => true,
+                Outcome::TooHigh => previous
+                    .parse::<i128>()
+                    .is_ok_and(|high| number.is_some_and(|n| n >= high)),
+                Outcome::TooLow => previous
+                    .parse::<i128>()
+                    .is_ok_and(|low| number.is_some_and(|n| n <= low)),
+                _ => false,
+            };
+            if refused {
+                return Some(format!("{previous} was already {outcome}"));
+            }
+        }
+        None
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, outcome: Outcome) {
+        self.tables
+            .entry(Self::key(day, part))
+            .or_default()
+            .insert(answer.into(), outcome.label().into());
+    }
+
+    pub fn wait_until(&self) -> Option<u64> {
+        self.tables.get("")?.get("wait_until")?.parse().ok()
+    }
+
+    pub fn set_wait_until(&mut self, until: u64) {
+        self.tables
+            .entry(String::new())
+            .or_default()
+            .insert("wait_until".into(), until.to_string());
+    }
+}
+
+/// The site's explanation, tags stripped.
+pub fn message(body: &str) -> String {
+    let article = match (body.find("<article>"), body.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &body[start..end],
+        _ => body,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Seconds the site asks us to wait before the next attempt, from either
+/// "You have 1m 30s left to wait" or "please wait 5 minutes".
+pub fn wait_seconds(body: &str) -> Option<u64> {
+    let text = message(body);
+    if let Some((before, _)) = text.split_once(" left to wait") {
+        let seconds = before
+            .split_whitespace()
+            .rev()
+            .map_while(|word| match word.strip_suffix('m') {
+                Some(minutes) => minutes.parse::<u64>().ok().map(|m| m * 60),
+                None => word.strip_suffix('s')?.parse().ok(),
+            })
+            .sum();
+        return Some(seconds);
+    }
+    let after = text.split("wait ").nth(1)?;
+    let mut words = after.split_whitespace();
+    let count = match words.next()? {
+        "one" | "a" => 1,
+        n => n.parse().ok()?,
+    };
+    words.next()?.starts_with("minute").then_some(count * 60)
+}
+
+fn url_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            b => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Sends `answer` unless `history` rules it out, recording the outcome.
+/// Returns the outcome and the site's message.
+pub fn send(
+    config: &Config,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<(Outcome, String)> {
+    if let Some(reason) = history.refusal(day, part, answer, now) {
+        return Err(format!("Not submitting {answer}: {reason}").into());
+    }
+    let url = format!("{}/{year}/day/{day}/answer", config.base_url);
+    let headers = config.headers()?;
+    let headers = headers
+        .iter()
+        .map(|(name, value)| (*name, value.as_str()))
+        .collect::<Vec<_>>();
+    let body = format!("level={part}&answer={}", url_encode(answer));
+    let response = http::post_form(&url, &headers, &body)?;
+    if !response.is_success() {
+        return Err(format!(
+            "POST {url} returned {}: {}",
+            response.status,
+            message(&response.body)
+        )
+        .into());
+    }
+    let outcome = Outcome::from_response(&response.body);
+    if let Some(seconds) = wait_seconds(&response.body) {
+        history.set_wait_until(now + seconds);
+    }
+    if outcome != Outcome::Wait {
+        history.record(day, part, answer, outcome);
+    }
+    history.save()?;
+    Ok((outcome, message(&response.body)))
+}
+
+pub fn submit(args: &[String]) -> Result<bool> {
+    let mut positional = Vec::new();
+    let mut base_url = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--base-url" => base_url = Some(args.next().ok_or("Missing value for --base-url")?),
+            _ => positional.push(arg),
+        }
+    }
+    let (year, day, part, answer) = match positional[..] {
+        [year, day, part] => (year, day, part, None),
+        [year, day, part, answer] => (year, day, part, Some(answer.clone())),
+        _ => return Err(USAGE.into()),
+    };
+    let (year, day, part) = (
+        year.parse::<u16>()?,
+        day.parse::<u8>()?,
+        part.parse::<Part>()?,
+    );
+
+    let root = repo_root();
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solution =
+                Day::find(year, day).ok_or(format!("No solution for {year} day {day}"))?;
+            let input = solution.load_input(&root)?;
+            let report = (solution.solve)(&input, &[part])?;
+            let answer = report.answers.into_iter().next().ok_or("No answer")?;
+            println!("Computed part {part}: {}", answer.value);
+            answer.value
+        }
+    };
+
+    let mut config = Config::load(&root)?;
+    if let Some(base_url) = base_url {
+        config.base_url = base_url.trim_end_matches('/').into();
+    }
+    let mut history = History::load(History::path(&root, year))?;
+    let (outcome, message) = send(&config, &mut history, year, day, part, &answer, now())?;
+    println!("{year} day {day:02} part {part}: {answer} is {outcome}");
+    if !message.is_empty() {
+        println!("{message}");
+    }
+    Ok(outcome == Outcome::Correct)
+}
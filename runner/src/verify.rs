@@ -0,0 +1,52 @@
+use aoc_common::{Part, Result};
+
+use crate::{
+    answers::Answers,
+    days::{repo_root, select},
+};
+
+const USAGE: &str = "Usage: aoc verify [YEAR [DAY]]";
+
+/// Checks every selected day against its stored answers, returning whether
+/// none of them mismatched or failed to run.
+pub fn verify(args: &[String]) -> Result<bool> {
+    let selected = select(args).map_err(|e| format!("{e}\n{USAGE}"))?;
+
+    let root = repo_root();
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in selected {
+        let answers = Answers::load(&root, day.year)?;
+        let label = format!("{} day {:02}", day.year, day.day);
+        let report = day
+            .load_input(&root)
+            .and_then(|input| (day.solve)(&input, &Part::ALL));
+        let report = match report {
+            Ok(report) => report,
+            Err(e) => {
+                println!("{label}: error: {e}");
+                failed += 1;
+                continue;
+            }
+        };
+        for answer in report.answers {
+            let label = format!("{label} part {}", answer.part);
+            match answers.get(day.day, answer.part) {
+                Some(expected) if expected == answer.value => {
+                    println!("{label}: pass");
+                    passed += 1;
+                }
+                Some(expected) => {
+                    println!("{label}: FAIL expected {expected}, got {}", answer.value);
+                    failed += 1;
+                }
+                None => {
+                    println!("{label}: missing, got {}", answer.value);
+                    missing += 1;
+                }
+            }
+        }
+    }
+    println!();
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    Ok(failed == 0)
+}
@@ -0,0 +1,241 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc_common::{input, json, toml, Part, Result};
+
+use crate::{
+    answers::part_key,
+    days::{crate_manifest, package_name, repo_root},
+    examples,
+};
+
+const USAGE: &str = "Usage: aoc watch YEAR DAY [--interval MS]";
+
+/// Modification time and length of every watched file.
+pub type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+/// Scans `paths`, descending into directories except `target`.
+pub fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    let mut pending = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if path.file_name().is_some_and(|name| name == "target") {
+                continue;
+            }
+            let entries = fs::read_dir(&path).into_iter().flatten().flatten();
+            pending.extend(entries.map(|entry| entry.path()));
+        } else {
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            snapshot.insert(path, (modified, metadata.len()));
+        }
+    }
+    snapshot
+}
+
+/// Files added, removed or modified between two snapshots.
+pub fn changed<'a>(before: &'a Snapshot, after: &'a Snapshot) -> Vec<&'a Path> {
+    let removed = before.keys().filter(|path| !after.contains_key(*path));
+    let touched = after
+        .iter()
+        .filter(|(path, stamp)| before.get(*path) != Some(stamp))
+        .map(|(path, _)| path);
+    removed.chain(touched).map(PathBuf::as_path).collect()
+}
+
+/// Runs the day's binary on `input`, returning its answers. Parameters are
+/// passed as `--NAME VALUE`, the day's own flags for them.
+fn solve(
+    root: &Path,
+    package: &str,
+    input: &Path,
+    parts: &[Part],
+    parameters: &toml::Table,
+) -> Result<Vec<(Part, String)>> {
+    let mut command = Command::new("cargo");
+    command
+        .args(["run", "--quiet", "--release", "--package", package, "--"])
+        .args(["--format", "json"])
+        .arg(input)
+        .current_dir(root);
+    for (name, value) in parameters {
+        command.arg(format!("--{name}")).arg(value);
+    }
+    if let [part] = parts {
+        command.args(["--part", &part.to_string()]);
+    }
+    let output = command.stderr(Stdio::piped()).output()?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim_end().into());
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| {
+            let fields = json::parse_flat(line).ok_or(format!("Unexpected output `{line}`"))?;
+            let part = fields.get("part").ok_or("Output without a part")?.parse()?;
+            let answer = fields.get("answer").ok_or("Output without an answer")?;
+            Ok((part, answer.clone()))
+        })
+        .collect()
+}
+
+/// Checks every example with expected answers, returning whether all passed.
+fn check_examples(root: &Path, package: &str, day_dir: &Path) -> Result<bool> {
+    let mut passed = true;
+    for example in examples::find_in(day_dir)? {
+        let expected = example.expected()?;
+        let parts = Part::ALL
+            .into_iter()
+            .filter(|part| expected.contains_key(part_key(*part)))
+            .collect::<Vec<_>>();
+        if parts.is_empty() {
+            continue;
+        }
+        let parameters = example.parameters()?;
+        let answers = match solve(root, package, &example.input, &parts, &parameters) {
+            Ok(answers) => answers,
+            Err(e) => {
+                println!("example {}: error: {e}", example.name);
+                passed = false;
+                continue;
+            }
+        };
+        for (part, answer) in answers {
+            match expected.get(part_key(part)) {
+                Some(expected) if *expected != answer => {
+                    println!(
+                        "example {} part {part}: FAIL expected {expected}, got {answer}",
+                        example.name
+                    );
+                    passed = false;
+                }
+                _ => println!("example {} part {part}: pass", example.name),
+            }
+        }
+    }
+    Ok(passed)
+}
+
+/// One rebuild and re-run, updating `previous` with the real answers.
+fn cycle(
+    root: &Path,
+    package: &str,
+    day_dir: &Path,
+    input: &Path,
+    previous: &mut BTreeMap<String, String>,
+) -> Result<()> {
+    let built = Command::new("cargo")
+        .args(["build", "--quiet", "--release", "--package", package])
+        .current_dir(root)
+        .status()?;
+    if !built.success() {
+        println!("build failed");
+        return Ok(());
+    }
+    if !check_examples(root, package, day_dir)? {
+        println!("examples failed, not running the real input");
+        return Ok(());
+    }
+    for (part, answer) in solve(root, package, input, &Part::ALL, &Default::default())? {
+        let change = match previous.insert(part.to_string(), answer.clone()) {
+            None => String::new(),
+            Some(old) if old == answer => " (unchanged)".into(),
+            Some(old) => format!(" (was {old})"),
+        };
+        println!("part {part}: {answer}{change}");
+    }
+    Ok(())
+}
+
+/// Rebuilds and re-runs a day whenever its sources, the shared crate, its
+/// examples or its input change, polling file metadata every `--interval`.
+pub fn watch(args: &[String]) -> Result<bool> {
+    let mut positional = Vec::new();
+    let mut interval = Duration::from_millis(500);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--interval" => {
+                let value = args.next().ok_or("Missing value for --interval")?;
+                interval = Duration::from_millis(value.parse()?);
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let (year, day) = match positional[..] {
+        [year, day] => (year.parse::<u16>()?, day.parse::<u8>()?),
+        _ => return Err(USAGE.into()),
+    };
+
+    let root = repo_root();
+    let manifest =
+        crate_manifest(&root, year, day).ok_or(format!("No Rust crate for {year} day {day}"))?;
+    let package = package_name(&manifest)?;
+    let crate_dir = manifest.parent().ok_or("Manifest without a directory")?;
+    let day_dir = input::day_dir(&root, year, day);
+    let input = input::default_path(&root, year, day);
+    let watched = [
+        crate_dir.to_path_buf(),
+        day_dir.join("examples"),
+        input.clone(),
+        root.join("common/src"),
+    ];
+
+    println!("watching {package}, press Ctrl-C to stop");
+    let mut previous = BTreeMap::new();
+    let mut before = snapshot(&watched);
+    if let Err(e) = cycle(&root, &package, &day_dir, &input, &mut previous) {
+        println!("error: {e}");
+    }
+    loop {
+        thread::sleep(interval);
+        let after = snapshot(&watched);
+        let changes = changed(&before, &after);
+        if changes.is_empty() {
+            continue;
+        }
+        println!();
+        for path in changes {
+            let path = path.strip_prefix(&root).unwrap_or(path);
+            println!("changed {}", path.display());
+        }
+        if let Err(e) = cycle(&root, &package, &day_dir, &input, &mut previous) {
+            println!("error: {e}");
+        }
+        before = after;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs};
+
+    use crate::watch::{changed, snapshot};
+
+    #[test]
+    fn test_changed() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("target")).unwrap();
+        let file = dir.join("lib.rs");
+        fs::write(&file, "fn a() {}").unwrap();
+        fs::write(dir.join("target/out"), "").unwrap();
+
+        let paths = [dir.clone()];
+        let before = snapshot(&paths);
+        assert_eq!(before.len(), 1);
+        assert!(changed(&before, &snapshot(&paths)).is_empty());
+        fs::write(&file, "fn a() { b() }").unwrap();
+        let after = snapshot(&paths);
+        assert_eq!(changed(&before, &after), [file.as_path()]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,25 @@
+use aoc::{
+    days::{days, repo_root},
+    examples,
+};
+
+#[test]
+fn test_examples() {
+    let root = repo_root();
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for day in days() {
+        for example in examples::find(&root, &day).unwrap() {
+            let label = format!("{} day {:02} {}", day.year, day.day, example.name);
+            match examples::check(&day, &example) {
+                Ok(mismatches) => {
+                    failures.extend(mismatches.into_iter().map(|m| format!("{label} {m}")))
+                }
+                Err(e) => failures.push(format!("{label}: {e}")),
+            }
+            checked += 1;
+        }
+    }
+    assert!(checked > 0, "No examples found");
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
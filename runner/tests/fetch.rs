@@ -0,0 +1,53 @@
+mod mock;
+
+use std::{env, fs};
+
+use aoc::{
+    config::Config,
+    fetch::{download, Fetched},
+};
+
+use mock::Server;
+
+#[test]
+fn test_fetch_caches_input() {
+    let server = Server::start(200, "1000\n2000\n\n3000\n");
+    let config = Config {
+        path: "unused".into(),
+        session: Some("secret".into()),
+        base_url: server.base_url.clone(),
+    };
+    let dir = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+    let path = dir.join("2022/01/input.txt");
+    let _ = fs::remove_dir_all(&dir);
+
+    assert_eq!(
+        download(&config, 2022, 1, &path).unwrap(),
+        Fetched::Downloaded
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n\n3000\n");
+    assert_eq!(download(&config, 2022, 1, &path).unwrap(), Fetched::Cached);
+
+    let requests = server.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2022/day/1/input");
+    assert!(requests[0]
+        .headers
+        .contains(&"Cookie: session=secret".into()));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_fetch_reports_http_errors() {
+    let server = Server::start(400, "Please log in");
+    let config = Config {
+        path: "unused".into(),
+        session: Some("expired".into()),
+        base_url: server.base_url.clone(),
+    };
+    let path = env::temp_dir().join(format!("aoc-fetch-error-{}", std::process::id()));
+    let error = download(&config, 2025, 8, &path).unwrap_err().to_string();
+    assert!(error.contains("400"), "{error}");
+    assert!(!path.exists());
+}
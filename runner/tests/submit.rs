@@ -0,0 +1,81 @@
+mod mock;
+
+use std::{env, fs, path::PathBuf};
+
+use aoc::{
+    config::Config,
+    submit::{send, wait_seconds, History, Outcome},
+};
+use aoc_common::Part;
+
+use mock::Server;
+
+fn setup(name: &str, server: &Server) -> (Config, PathBuf) {
+    let config = Config {
+        path: "unused".into(),
+        session: Some("secret".into()),
+        base_url: server.base_url.clone(),
+    };
+    let path = env::temp_dir().join(format!("aoc-submit-{name}-{}.toml", std::process::id()));
+    let _ = fs::remove_file(&path);
+    (config, path)
+}
+
+#[test]
+fn test_submit_records_bounds() {
+    let server = Server::start(
+        200,
+        "<main><article><p>That's not the right answer; your answer is too high.</p></article></main>",
+    );
+    let (config, path) = setup("bounds", &server);
+    let mut history = History::load(path.clone()).unwrap();
+
+    let (outcome, message) = send(&config, &mut history, 2022, 1, Part::One, "500", 0).unwrap();
+    assert_eq!(outcome, Outcome::TooHigh);
+    assert_eq!(
+        message,
+        "That's not the right answer; your answer is too high."
+    );
+    {
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2022/day/1/answer");
+        assert_eq!(requests[0].body, "level=1&answer=500");
+    }
+
+    // Reloaded from disk, the same and any higher answer are refused
+    // without contacting the server, a lower one or the other part is not.
+    let mut history = History::load(path.clone()).unwrap();
+    assert!(send(&config, &mut history, 2022, 1, Part::One, "500", 0).is_err());
+    assert!(send(&config, &mut history, 2022, 1, Part::One, "501", 0).is_err());
+    assert_eq!(server.requests.lock().unwrap().len(), 1);
+    assert!(history.refusal(1, Part::One, "499", 0).is_none());
+    assert!(history.refusal(1, Part::Two, "500", 0).is_none());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_submit_respects_wait() {
+    let server = Server::start(
+        200,
+        "<article><p>You gave an answer too recently. You have 1m 5s left to wait.</p></article>",
+    );
+    let (config, path) = setup("wait", &server);
+    let mut history = History::load(path.clone()).unwrap();
+
+    let (outcome, _) = send(&config, &mut history, 2025, 8, Part::Two, "42", 1000).unwrap();
+    assert_eq!(outcome, Outcome::Wait);
+    assert_eq!(history.wait_until(), Some(1065));
+    assert!(history.refusal(8, Part::Two, "42", 1064).is_some());
+    assert!(history.refusal(8, Part::Two, "42", 1065).is_none());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_wait_seconds() {
+    let wrong = "That's not the right answer. Please wait one minute before trying again.";
+    assert_eq!(wait_seconds(wrong), Some(60));
+    assert_eq!(wait_seconds("please wait 5 minutes before"), Some(300));
+    assert_eq!(wait_seconds("You have 34s left to wait."), Some(34));
+    assert_eq!(wait_seconds("That's the right answer!"), None);
+}